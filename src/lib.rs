@@ -11,12 +11,138 @@
 //! - `atomic_weight` (WIP!)
 //! - `n_valence_electrons` (for SMILES parsing/perception, not for general use)
 //! - `valence` (for SMILES parsing/perception, not for general use)
+//! - `valences` (for SMILES parsing/perception, not for general use)
+//! - `van_der_waals_radius`
+//! - `common_ion_charge`
+//! - `is_metal`
+//! - `is_nonmetal`
+//! - `is_metalloid`
+//! - `is_noble_gas`
+//! - `is_halogen`
+//! - `is_alkali_metal`
+//! - `is_alkaline_earth_metal`
+//! - `is_transition_metal`
+//! - `is_lanthanide`
+//! - `is_actinide`
+//! - `crustal_abundance_rank`
+//! - `standard_state`
+//! - `melting_point`
+//! - `boiling_point`
+//! - `catenation_tendency`
+//! - `density`
+//! - `discovery_country`
+//! - `electron_configuration`
+//! - `electron_configuration_noble_gas_shorthand`
+//! - `symbol_padded`
+//! - `first_ionization_energy`
+//! - `is_platinum_group`
+//! - `electron_affinity`
+//! - `common_oxidation_states`
+//! - `typical_oxide_formula`
+//! - `monoisotopic_mass`
+//! - `isotope_abundances`
+//! - `most_abundant_isotope`
+//! - `simple_color_class`
+//! - `from_atomic_number`
+//! - `as_atomic_number`
+//! - `atomic_number_radix`
+//! - `symbol`
+//! - `preceding_noble_gas`
+//! - `weight_matches`
+//! - `from_smiles_symbol`
+//! - `common_hybridizations`
+//! - `is_aromatic_organic_subset`
+//! - `is_organic_subset`
+//! - `known_isotopes`
+//! - `redox_tendency`
+//! - `cpk_color`
+//! - `principal_decay_mode`
+//! - `valence_shell_n`
+//! - `period`
+//! - `group`
+//! - `column_major_key`
+//! - `mendeleev_number`
+//! - `matches`
+//! - `atomic_weight_uncertainty`
+//! - `electronegativity`
+//! - `covalent_radius`
+//! - `feature_vector`
+//! - `atomic_weight_interval`
+//! - `group_name`
+//! - `swappable_symbol`
+//! - `is_real`
+//! - `nominal_mass`
+//! - `atomic_radius`
+//! - `implicit_hydrogens`
+//! - `has_stable_isotope`
+//! - `is_radioactive`
+//! - `from_atomic_number_strict`
+//! - `from_period_group`
+//! - `next_in_group`
+//! - `prev_in_group`
+//! - `next_in_period`
+//! - `prev_in_period`
+//! - `neutron_count`
+//! - `proton_count`
+//! - `electron_count`
+//! - `is_hypervalent`
+//! - `from_str_trimmed`
+//! - `from_xyz_symbol`
+//! - `is_main_group`
+//! - `name`
+//! - `parse_any`
+//! - `computed_average_mass`
+//! - `atomic_weight_with_composition`
+//! - `group_members`
+//! - `period_members`
+//! - `format_isotope_unicode`
+//! - `parse_isotope_unicode`
+//! - `category`
+//! - `unpaired_electrons`
+//! - `atomic_weight_or_nominal`
+//! - `from_ascii`
+//! - `cpk_color_hex`
+//! - `from_legacy_symbol`
+//!
+//! It also provides a few free functions:
+//! - `simple_salt_formula`
+//! - `const_valence`
+//! - `parse_formula`
+//! - `atom_economy`
+//! - `add_formulas`
+//! - `subtract_formula`
+//! - `weights_revision`
+//! - `molecular_weight`
+//! - `atom_percent`
+//! - `parse_symbols`
+//! - `heavier_of`
+//! - `heaviest`
+//! - `parse_bracket_element`
+//! - `labeled_molecular_weight`
+//! - `ascii_periodic_table`
+//! - `isotope_pattern`
+//! - `property_table`
+//! - `electronegativity_difference`
+//! - `bond_ionicity`
+//! - `total_atomic_weight`
 //!
 //! This library has its own `Error` enum with the following variants:
 //! - `InvalidAtomicNumber`
 //! - `InvalidAtomicSymbol`
 //! - `InvalidIsotope`
 //! - `InvalidFormalCharge`
+//! - `InvalidFormula`
+//! - `UnsupportedElement`
+//! - `InvalidComposition`
+//!
+//! The crate is `no_std` compatible (it still requires `alloc`) when the
+//! default `std` feature is disabled. Without `std`, the functions returning a
+//! `HashMap` and `bond_ionicity` are unavailable and `Error` does not
+//! implement `std::error::Error`.
+//!
+//! With the optional `serde` feature, Element implements `Serialize` and
+//! `Deserialize` as its atomic symbol, and the `serde` module provides helpers
+//! to store it as its atomic number instead.
 //!
 //! Here's some example code:
 //! ```rust
@@ -30,8 +156,22 @@
 //! assert_eq!(element.valence(0).unwrap(), 4);
 //! ```
 
-use std::fmt::Display;
-use std::str::FromStr;
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::borrow::ToOwned;
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::Display;
+use core::iter::Peekable;
+use core::str::Chars;
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
 
 /// Error enum for pertable.
 ///
@@ -44,16 +184,31 @@ use std::str::FromStr;
 ///     - Invoked when querying atomic_weight of an unknown isotope
 /// - `InvalidFormalCharge`
 ///     - Invoked when querying n_valence_electrons but n_valence_electrons < 0 or > 8
+/// - `InvalidFormula`
+///     - Invoked when parsing a malformed formula
+/// - `UnsupportedElement`
+///     - Invoked when querying a property that is not available for the element
+/// - `InvalidComposition`
+///     - Invoked when isotope fractions are negative or do not sum to 1.0
+///
+/// `InvalidIsotope`, `InvalidFormalCharge` and `UnsupportedElement` carry
+/// the offending Element itself. Earlier releases stored the atomic symbol of
+/// `InvalidIsotope` and `InvalidFormalCharge` as a String instead; code
+/// matching on that String should match on the Element, or call
+/// `Element::symbol` on it. The Display output is unchanged.
 #[derive(Debug, PartialEq, Eq)]
 pub enum Error {
     InvalidAtomicNumber(u8),
     InvalidAtomicSymbol(String),
-    InvalidIsotope(String, u16),
-    InvalidFormalCharge(String, i8),
+    InvalidIsotope(Element, u16),
+    InvalidFormalCharge(Element, i8),
+    InvalidFormula(String),
+    UnsupportedElement(Element),
+    InvalidComposition(Element),
 }
 
 impl Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Error::InvalidAtomicNumber(atomic_number) => {
                 write!(f, "invalid atomic number {atomic_number}")
@@ -61,22 +216,34 @@ impl Display for Error {
             Error::InvalidAtomicSymbol(atomic_symbol) => {
                 write!(f, "invalid atomic symbol {atomic_symbol}")
             }
-            Error::InvalidIsotope(atomic_symbol, isotope) => {
-                write!(f, "invalid isotope {isotope} for {atomic_symbol}")
+            Error::InvalidIsotope(element, isotope) => {
+                write!(f, "invalid isotope {isotope} for {element}")
             }
-            Error::InvalidFormalCharge(atomic_symbol, formal_charge) => {
-                write!(
-                    f,
-                    "invalid formal charge {formal_charge} for {atomic_symbol}"
-                )
+            Error::InvalidFormalCharge(element, formal_charge) => {
+                write!(f, "invalid formal charge {formal_charge} for {element}")
+            }
+            Error::InvalidFormula(formula) => write!(f, "invalid formula {formula}"),
+            Error::UnsupportedElement(element) => write!(f, "unsupported element {element}"),
+            Error::InvalidComposition(element) => {
+                write!(f, "invalid isotope composition for {element}")
             }
         }
     }
 }
 
-impl std::error::Error for Error {}
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        // no variant wraps a lower-level error
+        None
+    }
+}
 
 /// Element enum for the periodic table of elements.
+///
+/// `Element::Any` is a pseudo-element standing for any atom, like "*" in
+/// SMILES. It has atomic number 0 and symbol "*", and `is_real` is false only
+/// for it.
 /// 
 /// "Class methods" include:
 /// - `atomic_number`
@@ -84,8 +251,100 @@ impl std::error::Error for Error {}
 /// - `atomic_weight`
 /// - `n_valence_electrons`
 /// - `valence`
+/// - `valences`
+/// - `van_der_waals_radius`
+/// - `common_ion_charge`
+/// - `is_metal`
+/// - `is_nonmetal`
+/// - `is_metalloid`
+/// - `is_noble_gas`
+/// - `is_halogen`
+/// - `is_alkali_metal`
+/// - `is_alkaline_earth_metal`
+/// - `is_transition_metal`
+/// - `is_lanthanide`
+/// - `is_actinide`
+/// - `crustal_abundance_rank`
+/// - `standard_state`
+/// - `melting_point`
+/// - `boiling_point`
+/// - `catenation_tendency`
+/// - `density`
+/// - `discovery_country`
+/// - `electron_configuration`
+/// - `electron_configuration_noble_gas_shorthand`
+/// - `symbol_padded`
+/// - `first_ionization_energy`
+/// - `is_platinum_group`
+/// - `electron_affinity`
+/// - `common_oxidation_states`
+/// - `typical_oxide_formula`
+/// - `monoisotopic_mass`
+/// - `isotope_abundances`
+/// - `most_abundant_isotope`
+/// - `simple_color_class`
+/// - `from_atomic_number`
+/// - `as_atomic_number`
+/// - `atomic_number_radix`
+/// - `symbol`
+/// - `preceding_noble_gas`
+/// - `weight_matches`
+/// - `from_smiles_symbol`
+/// - `common_hybridizations`
+/// - `is_aromatic_organic_subset`
+/// - `is_organic_subset`
+/// - `known_isotopes`
+/// - `redox_tendency`
+/// - `cpk_color`
+/// - `principal_decay_mode`
+/// - `valence_shell_n`
+/// - `period`
+/// - `group`
+/// - `column_major_key`
+/// - `mendeleev_number`
+/// - `matches`
+/// - `atomic_weight_uncertainty`
+/// - `electronegativity`
+/// - `covalent_radius`
+/// - `feature_vector`
+/// - `atomic_weight_interval`
+/// - `group_name`
+/// - `swappable_symbol`
+/// - `is_real`
+/// - `nominal_mass`
+/// - `atomic_radius`
+/// - `implicit_hydrogens`
+/// - `has_stable_isotope`
+/// - `is_radioactive`
+/// - `from_atomic_number_strict`
+/// - `from_period_group`
+/// - `next_in_group`
+/// - `prev_in_group`
+/// - `next_in_period`
+/// - `prev_in_period`
+/// - `neutron_count`
+/// - `proton_count`
+/// - `electron_count`
+/// - `is_hypervalent`
+/// - `from_str_trimmed`
+/// - `from_xyz_symbol`
+/// - `is_main_group`
+/// - `name`
+/// - `parse_any`
+/// - `computed_average_mass`
+/// - `atomic_weight_with_composition`
+/// - `group_members`
+/// - `period_members`
+/// - `format_isotope_unicode`
+/// - `parse_isotope_unicode`
+/// - `category`
+/// - `unpaired_electrons`
+/// - `atomic_weight_or_nominal`
+/// - `from_ascii`
+/// - `cpk_color_hex`
+/// - `from_legacy_symbol`
 #[rustfmt::skip]
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
 pub enum Element {
     #[default]
     Any,
@@ -105,260 +364,252 @@ impl TryFrom<u8> for Element {
     type Error = Error;
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(Element::Any),
-            1 => Ok(Element::H),
-            2 => Ok(Element::He),
-            3 => Ok(Element::Li),
-            4 => Ok(Element::Be),
-            5 => Ok(Element::B),
-            6 => Ok(Element::C),
-            7 => Ok(Element::N),
-            8 => Ok(Element::O),
-            9 => Ok(Element::F),
-            10 => Ok(Element::Ne),
-            11 => Ok(Element::Na),
-            12 => Ok(Element::Mg),
-            13 => Ok(Element::Al),
-            14 => Ok(Element::Si),
-            15 => Ok(Element::P),
-            16 => Ok(Element::S),
-            17 => Ok(Element::Cl),
-            18 => Ok(Element::Ar),
-            19 => Ok(Element::K),
-            20 => Ok(Element::Ca),
-            21 => Ok(Element::Sc),
-            22 => Ok(Element::Ti),
-            23 => Ok(Element::V),
-            24 => Ok(Element::Cr),
-            25 => Ok(Element::Mn),
-            26 => Ok(Element::Fe),
-            27 => Ok(Element::Co),
-            28 => Ok(Element::Ni),
-            29 => Ok(Element::Cu),
-            30 => Ok(Element::Zn),
-            31 => Ok(Element::Ga),
-            32 => Ok(Element::Ge),
-            33 => Ok(Element::As),
-            34 => Ok(Element::Se),
-            35 => Ok(Element::Br),
-            36 => Ok(Element::Kr),
-            37 => Ok(Element::Rb),
-            38 => Ok(Element::Sr),
-            39 => Ok(Element::Y),
-            40 => Ok(Element::Zr),
-            41 => Ok(Element::Nb),
-            42 => Ok(Element::Mo),
-            43 => Ok(Element::Tc),
-            44 => Ok(Element::Ru),
-            45 => Ok(Element::Rh),
-            46 => Ok(Element::Pd),
-            47 => Ok(Element::Ag),
-            48 => Ok(Element::Cd),
-            49 => Ok(Element::In),
-            50 => Ok(Element::Sn),
-            51 => Ok(Element::Sb),
-            52 => Ok(Element::Te),
-            53 => Ok(Element::I),
-            54 => Ok(Element::Xe),
-            55 => Ok(Element::Cs),
-            56 => Ok(Element::Ba),
-            57 => Ok(Element::La),
-            58 => Ok(Element::Ce),
-            59 => Ok(Element::Pr),
-            60 => Ok(Element::Nd),
-            61 => Ok(Element::Pm),
-            62 => Ok(Element::Sm),
-            63 => Ok(Element::Eu),
-            64 => Ok(Element::Gd),
-            65 => Ok(Element::Tb),
-            66 => Ok(Element::Dy),
-            67 => Ok(Element::Ho),
-            68 => Ok(Element::Er),
-            69 => Ok(Element::Tm),
-            70 => Ok(Element::Yb),
-            71 => Ok(Element::Lu),
-            72 => Ok(Element::Hf),
-            73 => Ok(Element::Ta),
-            74 => Ok(Element::W),
-            75 => Ok(Element::Re),
-            76 => Ok(Element::Os),
-            77 => Ok(Element::Ir),
-            78 => Ok(Element::Pt),
-            79 => Ok(Element::Au),
-            80 => Ok(Element::Hg),
-            81 => Ok(Element::Tl),
-            82 => Ok(Element::Pb),
-            83 => Ok(Element::Bi),
-            84 => Ok(Element::Po),
-            85 => Ok(Element::At),
-            86 => Ok(Element::Rn),
-            87 => Ok(Element::Fr),
-            88 => Ok(Element::Ra),
-            89 => Ok(Element::Ac),
-            90 => Ok(Element::Th),
-            91 => Ok(Element::Pa),
-            92 => Ok(Element::U),
-            93 => Ok(Element::Np),
-            94 => Ok(Element::Pu),
-            95 => Ok(Element::Am),
-            96 => Ok(Element::Cm),
-            97 => Ok(Element::Bk),
-            98 => Ok(Element::Cf),
-            99 => Ok(Element::Es),
-            100 => Ok(Element::Fm),
-            101 => Ok(Element::Md),
-            102 => Ok(Element::No),
-            103 => Ok(Element::Lr),
-            104 => Ok(Element::Rf),
-            105 => Ok(Element::Db),
-            106 => Ok(Element::Sg),
-            107 => Ok(Element::Bh),
-            108 => Ok(Element::Hs),
-            109 => Ok(Element::Mt),
-            110 => Ok(Element::Ds),
-            111 => Ok(Element::Rg),
-            112 => Ok(Element::Cn),
-            113 => Ok(Element::Nh),
-            114 => Ok(Element::Fl),
-            115 => Ok(Element::Mc),
-            116 => Ok(Element::Lv),
-            117 => Ok(Element::Ts),
-            118 => Ok(Element::Og),
-            _ => Err(Error::InvalidAtomicNumber(value)),
-        }
+        Element::from_atomic_number(value).ok_or(Error::InvalidAtomicNumber(value))
+    }
+}
+
+/// Values that do not fit in a u8 are reported as
+/// `Error::InvalidAtomicNumber(u8::MAX)` rather than wrapping around.
+impl TryFrom<u16> for Element {
+    type Error = Error;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        u8::try_from(value)
+            .map_err(|_| Error::InvalidAtomicNumber(u8::MAX))
+            .and_then(Element::try_from)
+    }
+}
+
+/// Values that do not fit in a u8 are reported as
+/// `Error::InvalidAtomicNumber(u8::MAX)` rather than wrapping around.
+impl TryFrom<u32> for Element {
+    type Error = Error;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        u8::try_from(value)
+            .map_err(|_| Error::InvalidAtomicNumber(u8::MAX))
+            .and_then(Element::try_from)
+    }
+}
+
+/// Untyped integer literals fall back to i32, so this keeps calls such as
+/// `Element::try_from(6)` compiling alongside the other integer impls. Values
+/// that do not fit in a u8 are reported as `Error::InvalidAtomicNumber(u8::MAX)`.
+impl TryFrom<i32> for Element {
+    type Error = Error;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        u8::try_from(value)
+            .map_err(|_| Error::InvalidAtomicNumber(u8::MAX))
+            .and_then(Element::try_from)
     }
 }
 
 impl From<Element> for u8 {
     fn from(value: Element) -> Self {
-        match value {
-            Element::Any => 0,
-            Element::H => 1,
-            Element::He => 2,
-            Element::Li => 3,
-            Element::Be => 4,
-            Element::B => 5,
-            Element::C => 6,
-            Element::N => 7,
-            Element::O => 8,
-            Element::F => 9,
-            Element::Ne => 10,
-            Element::Na => 11,
-            Element::Mg => 12,
-            Element::Al => 13,
-            Element::Si => 14,
-            Element::P => 15,
-            Element::S => 16,
-            Element::Cl => 17,
-            Element::Ar => 18,
-            Element::K => 19,
-            Element::Ca => 20,
-            Element::Sc => 21,
-            Element::Ti => 22,
-            Element::V => 23,
-            Element::Cr => 24,
-            Element::Mn => 25,
-            Element::Fe => 26,
-            Element::Co => 27,
-            Element::Ni => 28,
-            Element::Cu => 29,
-            Element::Zn => 30,
-            Element::Ga => 31,
-            Element::Ge => 32,
-            Element::As => 33,
-            Element::Se => 34,
-            Element::Br => 35,
-            Element::Kr => 36,
-            Element::Rb => 37,
-            Element::Sr => 38,
-            Element::Y => 39,
-            Element::Zr => 40,
-            Element::Nb => 41,
-            Element::Mo => 42,
-            Element::Tc => 43,
-            Element::Ru => 44,
-            Element::Rh => 45,
-            Element::Pd => 46,
-            Element::Ag => 47,
-            Element::Cd => 48,
-            Element::In => 49,
-            Element::Sn => 50,
-            Element::Sb => 51,
-            Element::Te => 52,
-            Element::I => 53,
-            Element::Xe => 54,
-            Element::Cs => 55,
-            Element::Ba => 56,
-            Element::La => 57,
-            Element::Ce => 58,
-            Element::Pr => 59,
-            Element::Nd => 60,
-            Element::Pm => 61,
-            Element::Sm => 62,
-            Element::Eu => 63,
-            Element::Gd => 64,
-            Element::Tb => 65,
-            Element::Dy => 66,
-            Element::Ho => 67,
-            Element::Er => 68,
-            Element::Tm => 69,
-            Element::Yb => 70,
-            Element::Lu => 71,
-            Element::Hf => 72,
-            Element::Ta => 73,
-            Element::W => 74,
-            Element::Re => 75,
-            Element::Os => 76,
-            Element::Ir => 77,
-            Element::Pt => 78,
-            Element::Au => 79,
-            Element::Hg => 80,
-            Element::Tl => 81,
-            Element::Pb => 82,
-            Element::Bi => 83,
-            Element::Po => 84,
-            Element::At => 85,
-            Element::Rn => 86,
-            Element::Fr => 87,
-            Element::Ra => 88,
-            Element::Ac => 89,
-            Element::Th => 90,
-            Element::Pa => 91,
-            Element::U => 92,
-            Element::Np => 93,
-            Element::Pu => 94,
-            Element::Am => 95,
-            Element::Cm => 96,
-            Element::Bk => 97,
-            Element::Cf => 98,
-            Element::Es => 99,
-            Element::Fm => 100,
-            Element::Md => 101,
-            Element::No => 102,
-            Element::Lr => 103,
-            Element::Rf => 104,
-            Element::Db => 105,
-            Element::Sg => 106,
-            Element::Bh => 107,
-            Element::Hs => 108,
-            Element::Mt => 109,
-            Element::Ds => 110,
-            Element::Rg => 111,
-            Element::Cn => 112,
-            Element::Nh => 113,
-            Element::Fl => 114,
-            Element::Mc => 115,
-            Element::Lv => 116,
-            Element::Ts => 117,
-            Element::Og => 118,
-        }
+        value.as_atomic_number()
     }
 }
 
 impl From<&Element> for u8 {
     fn from(value: &Element) -> Self {
-        match value {
+        value.as_atomic_number()
+    }
+}
+
+impl FromStr for Element {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Element::from_ascii(s.as_bytes())
+    }
+}
+
+impl TryFrom<&str> for Element {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<String> for Element {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// Compares the atomic number of the Element, e.g. `Element::C == 6u8`.
+impl PartialEq<u8> for Element {
+    fn eq(&self, other: &u8) -> bool {
+        self.atomic_number() == *other
+    }
+}
+
+/// Compares the atomic symbol of the Element case-sensitively, e.g.
+/// `Element::C == "C"` but `Element::C != "c"`.
+impl PartialEq<&str> for Element {
+    fn eq(&self, other: &&str) -> bool {
+        self.symbol() == *other
+    }
+}
+
+/// Writes the atomic symbol of the Element, or its full name with the
+/// alternate flag, e.g. "Fe" for `{}` and "Iron" for `{:#}`.
+impl Display for Element {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            write!(f, "{}", self.name())
+        } else {
+            write!(f, "{}", self.symbol())
+        }
+    }
+}
+
+impl Element {
+    /// Returns the Element with atomic number n, or None if n > 118. Unlike
+    /// `TryFrom<u8>` this is usable in const contexts.
+    pub const fn from_atomic_number(n: u8) -> Option<Element> {
+        match n {
+            0 => Some(Element::Any),
+            1 => Some(Element::H),
+            2 => Some(Element::He),
+            3 => Some(Element::Li),
+            4 => Some(Element::Be),
+            5 => Some(Element::B),
+            6 => Some(Element::C),
+            7 => Some(Element::N),
+            8 => Some(Element::O),
+            9 => Some(Element::F),
+            10 => Some(Element::Ne),
+            11 => Some(Element::Na),
+            12 => Some(Element::Mg),
+            13 => Some(Element::Al),
+            14 => Some(Element::Si),
+            15 => Some(Element::P),
+            16 => Some(Element::S),
+            17 => Some(Element::Cl),
+            18 => Some(Element::Ar),
+            19 => Some(Element::K),
+            20 => Some(Element::Ca),
+            21 => Some(Element::Sc),
+            22 => Some(Element::Ti),
+            23 => Some(Element::V),
+            24 => Some(Element::Cr),
+            25 => Some(Element::Mn),
+            26 => Some(Element::Fe),
+            27 => Some(Element::Co),
+            28 => Some(Element::Ni),
+            29 => Some(Element::Cu),
+            30 => Some(Element::Zn),
+            31 => Some(Element::Ga),
+            32 => Some(Element::Ge),
+            33 => Some(Element::As),
+            34 => Some(Element::Se),
+            35 => Some(Element::Br),
+            36 => Some(Element::Kr),
+            37 => Some(Element::Rb),
+            38 => Some(Element::Sr),
+            39 => Some(Element::Y),
+            40 => Some(Element::Zr),
+            41 => Some(Element::Nb),
+            42 => Some(Element::Mo),
+            43 => Some(Element::Tc),
+            44 => Some(Element::Ru),
+            45 => Some(Element::Rh),
+            46 => Some(Element::Pd),
+            47 => Some(Element::Ag),
+            48 => Some(Element::Cd),
+            49 => Some(Element::In),
+            50 => Some(Element::Sn),
+            51 => Some(Element::Sb),
+            52 => Some(Element::Te),
+            53 => Some(Element::I),
+            54 => Some(Element::Xe),
+            55 => Some(Element::Cs),
+            56 => Some(Element::Ba),
+            57 => Some(Element::La),
+            58 => Some(Element::Ce),
+            59 => Some(Element::Pr),
+            60 => Some(Element::Nd),
+            61 => Some(Element::Pm),
+            62 => Some(Element::Sm),
+            63 => Some(Element::Eu),
+            64 => Some(Element::Gd),
+            65 => Some(Element::Tb),
+            66 => Some(Element::Dy),
+            67 => Some(Element::Ho),
+            68 => Some(Element::Er),
+            69 => Some(Element::Tm),
+            70 => Some(Element::Yb),
+            71 => Some(Element::Lu),
+            72 => Some(Element::Hf),
+            73 => Some(Element::Ta),
+            74 => Some(Element::W),
+            75 => Some(Element::Re),
+            76 => Some(Element::Os),
+            77 => Some(Element::Ir),
+            78 => Some(Element::Pt),
+            79 => Some(Element::Au),
+            80 => Some(Element::Hg),
+            81 => Some(Element::Tl),
+            82 => Some(Element::Pb),
+            83 => Some(Element::Bi),
+            84 => Some(Element::Po),
+            85 => Some(Element::At),
+            86 => Some(Element::Rn),
+            87 => Some(Element::Fr),
+            88 => Some(Element::Ra),
+            89 => Some(Element::Ac),
+            90 => Some(Element::Th),
+            91 => Some(Element::Pa),
+            92 => Some(Element::U),
+            93 => Some(Element::Np),
+            94 => Some(Element::Pu),
+            95 => Some(Element::Am),
+            96 => Some(Element::Cm),
+            97 => Some(Element::Bk),
+            98 => Some(Element::Cf),
+            99 => Some(Element::Es),
+            100 => Some(Element::Fm),
+            101 => Some(Element::Md),
+            102 => Some(Element::No),
+            103 => Some(Element::Lr),
+            104 => Some(Element::Rf),
+            105 => Some(Element::Db),
+            106 => Some(Element::Sg),
+            107 => Some(Element::Bh),
+            108 => Some(Element::Hs),
+            109 => Some(Element::Mt),
+            110 => Some(Element::Ds),
+            111 => Some(Element::Rg),
+            112 => Some(Element::Cn),
+            113 => Some(Element::Nh),
+            114 => Some(Element::Fl),
+            115 => Some(Element::Mc),
+            116 => Some(Element::Lv),
+            117 => Some(Element::Ts),
+            118 => Some(Element::Og),
+            _ => None,
+        }
+    }
+
+    /// Returns the Element with atomic number n like `TryFrom<u8>`, but
+    /// rejects 0 so that the pseudo-element Any is never produced from
+    /// numeric data.
+    pub fn from_atomic_number_strict(n: u8) -> Result<Element, Error> {
+        match Element::from_atomic_number(n) {
+            Some(element) if element.is_real() => Ok(element),
+            _ => Err(Error::InvalidAtomicNumber(n)),
+        }
+    }
+
+    /// Returns the atomic number of the Element. Unlike `atomic_number` this
+    /// is usable in const contexts.
+    pub const fn as_atomic_number(&self) -> u8 {
+        match self {
             Element::Any => 0,
             Element::H => 1,
             Element::He => 2,
@@ -480,278 +731,150 @@ impl From<&Element> for u8 {
             Element::Og => 118,
         }
     }
-}
-
-impl FromStr for Element {
-    type Err = Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.to_lowercase().as_str() {
-            "*" => Ok(Element::Any),
-            "h" => Ok(Element::H),
-            "he" => Ok(Element::He),
-            "li" => Ok(Element::Li),
-            "be" => Ok(Element::Be),
-            "b" => Ok(Element::B),
-            "c" => Ok(Element::C),
-            "n" => Ok(Element::N),
-            "o" => Ok(Element::O),
-            "f" => Ok(Element::F),
-            "ne" => Ok(Element::Ne),
-            "na" => Ok(Element::Na),
-            "mg" => Ok(Element::Mg),
-            "al" => Ok(Element::Al),
-            "si" => Ok(Element::Si),
-            "p" => Ok(Element::P),
-            "s" => Ok(Element::S),
-            "cl" => Ok(Element::Cl),
-            "ar" => Ok(Element::Ar),
-            "k" => Ok(Element::K),
-            "ca" => Ok(Element::Ca),
-            "sc" => Ok(Element::Sc),
-            "ti" => Ok(Element::Ti),
-            "v" => Ok(Element::V),
-            "cr" => Ok(Element::Cr),
-            "mn" => Ok(Element::Mn),
-            "fe" => Ok(Element::Fe),
-            "co" => Ok(Element::Co),
-            "ni" => Ok(Element::Ni),
-            "cu" => Ok(Element::Cu),
-            "zn" => Ok(Element::Zn),
-            "ga" => Ok(Element::Ga),
-            "ge" => Ok(Element::Ge),
-            "as" => Ok(Element::As),
-            "se" => Ok(Element::Se),
-            "br" => Ok(Element::Br),
-            "kr" => Ok(Element::Kr),
-            "rb" => Ok(Element::Rb),
-            "sr" => Ok(Element::Sr),
-            "y" => Ok(Element::Y),
-            "zr" => Ok(Element::Zr),
-            "nb" => Ok(Element::Nb),
-            "mo" => Ok(Element::Mo),
-            "tc" => Ok(Element::Tc),
-            "ru" => Ok(Element::Ru),
-            "rh" => Ok(Element::Rh),
-            "pd" => Ok(Element::Pd),
-            "ag" => Ok(Element::Ag),
-            "cd" => Ok(Element::Cd),
-            "in" => Ok(Element::In),
-            "sn" => Ok(Element::Sn),
-            "sb" => Ok(Element::Sb),
-            "te" => Ok(Element::Te),
-            "i" => Ok(Element::I),
-            "xe" => Ok(Element::Xe),
-            "cs" => Ok(Element::Cs),
-            "ba" => Ok(Element::Ba),
-            "la" => Ok(Element::La),
-            "ce" => Ok(Element::Ce),
-            "pr" => Ok(Element::Pr),
-            "nd" => Ok(Element::Nd),
-            "pm" => Ok(Element::Pm),
-            "sm" => Ok(Element::Sm),
-            "eu" => Ok(Element::Eu),
-            "gd" => Ok(Element::Gd),
-            "tb" => Ok(Element::Tb),
-            "dy" => Ok(Element::Dy),
-            "ho" => Ok(Element::Ho),
-            "er" => Ok(Element::Er),
-            "tm" => Ok(Element::Tm),
-            "yb" => Ok(Element::Yb),
-            "lu" => Ok(Element::Lu),
-            "hf" => Ok(Element::Hf),
-            "ta" => Ok(Element::Ta),
-            "w" => Ok(Element::W),
-            "re" => Ok(Element::Re),
-            "os" => Ok(Element::Os),
-            "ir" => Ok(Element::Ir),
-            "pt" => Ok(Element::Pt),
-            "au" => Ok(Element::Au),
-            "hg" => Ok(Element::Hg),
-            "tl" => Ok(Element::Tl),
-            "pb" => Ok(Element::Pb),
-            "bi" => Ok(Element::Bi),
-            "po" => Ok(Element::Po),
-            "at" => Ok(Element::At),
-            "rn" => Ok(Element::Rn),
-            "fr" => Ok(Element::Fr),
-            "ra" => Ok(Element::Ra),
-            "ac" => Ok(Element::Ac),
-            "th" => Ok(Element::Th),
-            "pa" => Ok(Element::Pa),
-            "u" => Ok(Element::U),
-            "np" => Ok(Element::Np),
-            "pu" => Ok(Element::Pu),
-            "am" => Ok(Element::Am),
-            "cm" => Ok(Element::Cm),
-            "bk" => Ok(Element::Bk),
-            "cf" => Ok(Element::Cf),
-            "es" => Ok(Element::Es),
-            "fm" => Ok(Element::Fm),
-            "md" => Ok(Element::Md),
-            "no" => Ok(Element::No),
-            "lr" => Ok(Element::Lr),
-            "rf" => Ok(Element::Rf),
-            "db" => Ok(Element::Db),
-            "sg" => Ok(Element::Sg),
-            "bh" => Ok(Element::Bh),
-            "hs" => Ok(Element::Hs),
-            "mt" => Ok(Element::Mt),
-            "ds" => Ok(Element::Ds),
-            "rg" => Ok(Element::Rg),
-            "cn" => Ok(Element::Cn),
-            "nh" => Ok(Element::Nh),
-            "fl" => Ok(Element::Fl),
-            "mc" => Ok(Element::Mc),
-            "lv" => Ok(Element::Lv),
-            "ts" => Ok(Element::Ts),
-            "og" => Ok(Element::Og),
-            _ => Err(Error::InvalidAtomicSymbol(s.to_owned())),
-        }
-    }
-}
-
-impl Display for Element {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Element::Any => write!(f, "*"),
-            Element::H => write!(f, "H"),
-            Element::He => write!(f, "He"),
-            Element::Li => write!(f, "Li"),
-            Element::Be => write!(f, "Be"),
-            Element::B => write!(f, "B"),
-            Element::C => write!(f, "C"),
-            Element::N => write!(f, "N"),
-            Element::O => write!(f, "O"),
-            Element::F => write!(f, "F"),
-            Element::Ne => write!(f, "Ne"),
-            Element::Na => write!(f, "Na"),
-            Element::Mg => write!(f, "Mg"),
-            Element::Al => write!(f, "Al"),
-            Element::Si => write!(f, "Si"),
-            Element::P => write!(f, "P"),
-            Element::S => write!(f, "S"),
-            Element::Cl => write!(f, "Cl"),
-            Element::Ar => write!(f, "Ar"),
-            Element::K => write!(f, "K"),
-            Element::Ca => write!(f, "Ca"),
-            Element::Sc => write!(f, "Sc"),
-            Element::Ti => write!(f, "Ti"),
-            Element::V => write!(f, "V"),
-            Element::Cr => write!(f, "Cr"),
-            Element::Mn => write!(f, "Mn"),
-            Element::Fe => write!(f, "Fe"),
-            Element::Co => write!(f, "Co"),
-            Element::Ni => write!(f, "Ni"),
-            Element::Cu => write!(f, "Cu"),
-            Element::Zn => write!(f, "Zn"),
-            Element::Ga => write!(f, "Ga"),
-            Element::Ge => write!(f, "Ge"),
-            Element::As => write!(f, "As"),
-            Element::Se => write!(f, "Se"),
-            Element::Br => write!(f, "Br"),
-            Element::Kr => write!(f, "Kr"),
-            Element::Rb => write!(f, "Rb"),
-            Element::Sr => write!(f, "Sr"),
-            Element::Y => write!(f, "Y"),
-            Element::Zr => write!(f, "Zr"),
-            Element::Nb => write!(f, "Nb"),
-            Element::Mo => write!(f, "Mo"),
-            Element::Tc => write!(f, "Tc"),
-            Element::Ru => write!(f, "Ru"),
-            Element::Rh => write!(f, "Rh"),
-            Element::Pd => write!(f, "Pd"),
-            Element::Ag => write!(f, "Ag"),
-            Element::Cd => write!(f, "Cd"),
-            Element::In => write!(f, "In"),
-            Element::Sn => write!(f, "Sn"),
-            Element::Sb => write!(f, "Sb"),
-            Element::Te => write!(f, "Te"),
-            Element::I => write!(f, "I"),
-            Element::Xe => write!(f, "Xe"),
-            Element::Cs => write!(f, "Cs"),
-            Element::Ba => write!(f, "Ba"),
-            Element::La => write!(f, "La"),
-            Element::Ce => write!(f, "Ce"),
-            Element::Pr => write!(f, "Pr"),
-            Element::Nd => write!(f, "Nd"),
-            Element::Pm => write!(f, "Pm"),
-            Element::Sm => write!(f, "Sm"),
-            Element::Eu => write!(f, "Eu"),
-            Element::Gd => write!(f, "Gd"),
-            Element::Tb => write!(f, "Tb"),
-            Element::Dy => write!(f, "Dy"),
-            Element::Ho => write!(f, "Ho"),
-            Element::Er => write!(f, "Er"),
-            Element::Tm => write!(f, "Tm"),
-            Element::Yb => write!(f, "Yb"),
-            Element::Lu => write!(f, "Lu"),
-            Element::Hf => write!(f, "Hf"),
-            Element::Ta => write!(f, "Ta"),
-            Element::W => write!(f, "W"),
-            Element::Re => write!(f, "Re"),
-            Element::Os => write!(f, "Os"),
-            Element::Ir => write!(f, "Ir"),
-            Element::Pt => write!(f, "Pt"),
-            Element::Au => write!(f, "Au"),
-            Element::Hg => write!(f, "Hg"),
-            Element::Tl => write!(f, "Tl"),
-            Element::Pb => write!(f, "Pb"),
-            Element::Bi => write!(f, "Bi"),
-            Element::Po => write!(f, "Po"),
-            Element::At => write!(f, "At"),
-            Element::Rn => write!(f, "Rn"),
-            Element::Fr => write!(f, "Fr"),
-            Element::Ra => write!(f, "Ra"),
-            Element::Ac => write!(f, "Ac"),
-            Element::Th => write!(f, "Th"),
-            Element::Pa => write!(f, "Pa"),
-            Element::U => write!(f, "U"),
-            Element::Np => write!(f, "Np"),
-            Element::Pu => write!(f, "Pu"),
-            Element::Am => write!(f, "Am"),
-            Element::Cm => write!(f, "Cm"),
-            Element::Bk => write!(f, "Bk"),
-            Element::Cf => write!(f, "Cf"),
-            Element::Es => write!(f, "Es"),
-            Element::Fm => write!(f, "Fm"),
-            Element::Md => write!(f, "Md"),
-            Element::No => write!(f, "No"),
-            Element::Lr => write!(f, "Lr"),
-            Element::Rf => write!(f, "Rf"),
-            Element::Db => write!(f, "Db"),
-            Element::Sg => write!(f, "Sg"),
-            Element::Bh => write!(f, "Bh"),
-            Element::Hs => write!(f, "Hs"),
-            Element::Mt => write!(f, "Mt"),
-            Element::Ds => write!(f, "Ds"),
-            Element::Rg => write!(f, "Rg"),
-            Element::Cn => write!(f, "Cn"),
-            Element::Nh => write!(f, "Nh"),
-            Element::Fl => write!(f, "Fl"),
-            Element::Mc => write!(f, "Mc"),
-            Element::Lv => write!(f, "Lv"),
-            Element::Ts => write!(f, "Ts"),
-            Element::Og => write!(f, "Og"),
-        }
-    }
-}
 
-impl Element {
     /// Returns the atomic number of the Element.
     pub fn atomic_number(&self) -> u8 {
         u8::from(self)
     }
 
-    /// Returns the atomic symbol of the Element.
+    /// Returns the atomic symbol of the Element as an owned String. Prefer
+    /// `symbol` in hot loops as it does not allocate.
     pub fn atomic_symbol(&self) -> String {
-        self.to_string()
+        self.symbol().to_owned()
+    }
+
+    /// Returns the atomic symbol of the Element without allocating.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Element::Any => "*",
+            Element::H => "H",
+            Element::He => "He",
+            Element::Li => "Li",
+            Element::Be => "Be",
+            Element::B => "B",
+            Element::C => "C",
+            Element::N => "N",
+            Element::O => "O",
+            Element::F => "F",
+            Element::Ne => "Ne",
+            Element::Na => "Na",
+            Element::Mg => "Mg",
+            Element::Al => "Al",
+            Element::Si => "Si",
+            Element::P => "P",
+            Element::S => "S",
+            Element::Cl => "Cl",
+            Element::Ar => "Ar",
+            Element::K => "K",
+            Element::Ca => "Ca",
+            Element::Sc => "Sc",
+            Element::Ti => "Ti",
+            Element::V => "V",
+            Element::Cr => "Cr",
+            Element::Mn => "Mn",
+            Element::Fe => "Fe",
+            Element::Co => "Co",
+            Element::Ni => "Ni",
+            Element::Cu => "Cu",
+            Element::Zn => "Zn",
+            Element::Ga => "Ga",
+            Element::Ge => "Ge",
+            Element::As => "As",
+            Element::Se => "Se",
+            Element::Br => "Br",
+            Element::Kr => "Kr",
+            Element::Rb => "Rb",
+            Element::Sr => "Sr",
+            Element::Y => "Y",
+            Element::Zr => "Zr",
+            Element::Nb => "Nb",
+            Element::Mo => "Mo",
+            Element::Tc => "Tc",
+            Element::Ru => "Ru",
+            Element::Rh => "Rh",
+            Element::Pd => "Pd",
+            Element::Ag => "Ag",
+            Element::Cd => "Cd",
+            Element::In => "In",
+            Element::Sn => "Sn",
+            Element::Sb => "Sb",
+            Element::Te => "Te",
+            Element::I => "I",
+            Element::Xe => "Xe",
+            Element::Cs => "Cs",
+            Element::Ba => "Ba",
+            Element::La => "La",
+            Element::Ce => "Ce",
+            Element::Pr => "Pr",
+            Element::Nd => "Nd",
+            Element::Pm => "Pm",
+            Element::Sm => "Sm",
+            Element::Eu => "Eu",
+            Element::Gd => "Gd",
+            Element::Tb => "Tb",
+            Element::Dy => "Dy",
+            Element::Ho => "Ho",
+            Element::Er => "Er",
+            Element::Tm => "Tm",
+            Element::Yb => "Yb",
+            Element::Lu => "Lu",
+            Element::Hf => "Hf",
+            Element::Ta => "Ta",
+            Element::W => "W",
+            Element::Re => "Re",
+            Element::Os => "Os",
+            Element::Ir => "Ir",
+            Element::Pt => "Pt",
+            Element::Au => "Au",
+            Element::Hg => "Hg",
+            Element::Tl => "Tl",
+            Element::Pb => "Pb",
+            Element::Bi => "Bi",
+            Element::Po => "Po",
+            Element::At => "At",
+            Element::Rn => "Rn",
+            Element::Fr => "Fr",
+            Element::Ra => "Ra",
+            Element::Ac => "Ac",
+            Element::Th => "Th",
+            Element::Pa => "Pa",
+            Element::U => "U",
+            Element::Np => "Np",
+            Element::Pu => "Pu",
+            Element::Am => "Am",
+            Element::Cm => "Cm",
+            Element::Bk => "Bk",
+            Element::Cf => "Cf",
+            Element::Es => "Es",
+            Element::Fm => "Fm",
+            Element::Md => "Md",
+            Element::No => "No",
+            Element::Lr => "Lr",
+            Element::Rf => "Rf",
+            Element::Db => "Db",
+            Element::Sg => "Sg",
+            Element::Bh => "Bh",
+            Element::Hs => "Hs",
+            Element::Mt => "Mt",
+            Element::Ds => "Ds",
+            Element::Rg => "Rg",
+            Element::Cn => "Cn",
+            Element::Nh => "Nh",
+            Element::Fl => "Fl",
+            Element::Mc => "Mc",
+            Element::Lv => "Lv",
+            Element::Ts => "Ts",
+            Element::Og => "Og",
+        }
     }
 
     /// Returns the atomic weight of the Element. If isotope is None, the
-    /// standard atomic weight is given.
+    /// standard atomic weight is given. Elements without a standard atomic
+    /// weight give the mass number of their longest-lived isotope instead,
+    /// e.g. 98 for Tc.
     ///
-    /// Weights are sourced from NIST.
+    /// Weights are sourced from NIST, with standard atomic weights following
+    /// the CIAAW table of `WEIGHTS_SOURCE_YEAR`.
     pub fn atomic_weight(&self, isotope: Option<u16>) -> Result<f64, Error> {
         match self {
             Element::Any => Ok(0.0),
@@ -761,7 +884,7 @@ impl Element {
                     1 => Ok(1.007_825),
                     2 => Ok(2.014_102),
                     3 => Ok(3.016_049),
-                    _ => Err(Error::InvalidIsotope(self.atomic_symbol(), isotope)),
+                    _ => Err(Error::InvalidIsotope(*self, isotope)),
                 },
             },
             Element::He => match isotope {
@@ -769,7 +892,7 @@ impl Element {
                 Some(isotope) => match isotope {
                     3 => Ok(3.016_029),
                     4 => Ok(4.002_603),
-                    _ => Err(Error::InvalidIsotope(self.atomic_symbol(), isotope)),
+                    _ => Err(Error::InvalidIsotope(*self, isotope)),
                 },
             },
             Element::Li => match isotope {
@@ -777,14 +900,14 @@ impl Element {
                 Some(isotope) => match isotope {
                     6 => Ok(6.015_123),
                     7 => Ok(7.016_003),
-                    _ => Err(Error::InvalidIsotope(self.atomic_symbol(), isotope)),
+                    _ => Err(Error::InvalidIsotope(*self, isotope)),
                 },
             },
             Element::Be => match isotope {
                 None => Ok(9.012_183),
                 Some(isotope) => match isotope {
                     9 => Ok(9.012_183),
-                    _ => Err(Error::InvalidIsotope(self.atomic_symbol(), isotope)),
+                    _ => Err(Error::InvalidIsotope(*self, isotope)),
                 },
             },
             Element::B => match isotope {
@@ -792,7 +915,7 @@ impl Element {
                 Some(isotope) => match isotope {
                     10 => Ok(10.012_937),
                     11 => Ok(11.009_305),
-                    _ => Err(Error::InvalidIsotope(self.atomic_symbol(), isotope)),
+                    _ => Err(Error::InvalidIsotope(*self, isotope)),
                 },
             },
             Element::C => match isotope {
@@ -801,7 +924,7 @@ impl Element {
                     12 => Ok(12.000_000),
                     13 => Ok(13.003_355),
                     14 => Ok(14.003_242),
-                    _ => Err(Error::InvalidIsotope(self.atomic_symbol(), isotope)),
+                    _ => Err(Error::InvalidIsotope(*self, isotope)),
                 },
             },
             Element::N => match isotope {
@@ -809,7 +932,7 @@ impl Element {
                 Some(isotope) => match isotope {
                     14 => Ok(14.003_074),
                     15 => Ok(15.000_109),
-                    _ => Err(Error::InvalidIsotope(self.atomic_symbol(), isotope)),
+                    _ => Err(Error::InvalidIsotope(*self, isotope)),
                 },
             },
             Element::O => match isotope {
@@ -818,30 +941,30 @@ impl Element {
                     16 => Ok(15.994_915),
                     17 => Ok(16.999_132),
                     18 => Ok(17.999_160),
-                    _ => Err(Error::InvalidIsotope(self.atomic_symbol(), isotope)),
+                    _ => Err(Error::InvalidIsotope(*self, isotope)),
                 },
             },
             Element::F => match isotope {
                 None => Ok(18.998_403),
                 Some(isotope) => match isotope {
                     19 => Ok(18.998_403),
-                    _ => Err(Error::InvalidIsotope(self.atomic_symbol(), isotope)),
+                    _ => Err(Error::InvalidIsotope(*self, isotope)),
                 },
             },
             Element::Ne => match isotope {
                 None => Ok(20.179_7),
                 Some(isotope) => match isotope {
-                    20 => Ok(19.997_440),
+                    20 => Ok(19.992_440),
                     21 => Ok(20.993_847),
                     22 => Ok(21.991_385),
-                    _ => Err(Error::InvalidIsotope(self.atomic_symbol(), isotope)),
+                    _ => Err(Error::InvalidIsotope(*self, isotope)),
                 },
             },
             Element::Na => match isotope {
                 None => Ok(22.989_769),
                 Some(isotope) => match isotope {
                     23 => Ok(22.989_769),
-                    _ => Err(Error::InvalidIsotope(self.atomic_symbol(), isotope)),
+                    _ => Err(Error::InvalidIsotope(*self, isotope)),
                 },
             },
             Element::Mg => match isotope {
@@ -850,14 +973,14 @@ impl Element {
                     24 => Ok(23.985_042),
                     25 => Ok(24.985_837),
                     26 => Ok(25.982_593),
-                    _ => Err(Error::InvalidIsotope(self.atomic_symbol(), isotope)),
+                    _ => Err(Error::InvalidIsotope(*self, isotope)),
                 },
             },
             Element::Al => match isotope {
                 None => Ok(26.981_539),
                 Some(isotope) => match isotope {
                     27 => Ok(26.981_538),
-                    _ => Err(Error::InvalidIsotope(self.atomic_symbol(), isotope)),
+                    _ => Err(Error::InvalidIsotope(*self, isotope)),
                 },
             },
             Element::Si => match isotope {
@@ -866,14 +989,14 @@ impl Element {
                     28 => Ok(27.976_927),
                     29 => Ok(28.976_495),
                     30 => Ok(29.973_770),
-                    _ => Err(Error::InvalidIsotope(self.atomic_symbol(), isotope)),
+                    _ => Err(Error::InvalidIsotope(*self, isotope)),
                 },
             },
             Element::P => match isotope {
                 None => Ok(30.973_762),
                 Some(isotope) => match isotope {
                     31 => Ok(30.973_762),
-                    _ => Err(Error::InvalidIsotope(self.atomic_symbol(), isotope)),
+                    _ => Err(Error::InvalidIsotope(*self, isotope)),
                 },
             },
             Element::S => match isotope {
@@ -883,7 +1006,7 @@ impl Element {
                     33 => Ok(32.971_459),
                     34 => Ok(33.967_867),
                     36 => Ok(35.967_081),
-                    _ => Err(Error::InvalidIsotope(self.atomic_symbol(), isotope)),
+                    _ => Err(Error::InvalidIsotope(*self, isotope)),
                 },
             },
             Element::Cl => match isotope {
@@ -891,7 +1014,7 @@ impl Element {
                 Some(isotope) => match isotope {
                     35 => Ok(34.968_853),
                     37 => Ok(36.965_093),
-                    _ => Err(Error::InvalidIsotope(self.atomic_symbol(), isotope)),
+                    _ => Err(Error::InvalidIsotope(*self, isotope)),
                 },
             },
             Element::Ar => match isotope {
@@ -900,7 +1023,7 @@ impl Element {
                     36 => Ok(35.967_545),
                     38 => Ok(37.962_732),
                     40 => Ok(39.962_383),
-                    _ => Err(Error::InvalidIsotope(self.atomic_symbol(), isotope)),
+                    _ => Err(Error::InvalidIsotope(*self, isotope)),
                 },
             },
             Element::Br => match isotope {
@@ -908,143 +1031,6110 @@ impl Element {
                 Some(isotope) => match isotope {
                     79 => Ok(78.918_338),
                     81 => Ok(80.916_290),
-                    _ => Err(Error::InvalidIsotope(self.atomic_symbol(), isotope)),
+                    _ => Err(Error::InvalidIsotope(*self, isotope)),
                 },
             },
             Element::I => match isotope {
                 None => Ok(126.904_473),
                 Some(isotope) => match isotope {
                     127 => Ok(126.904_472),
-                    _ => Err(Error::InvalidIsotope(self.atomic_symbol(), isotope)),
+                    _ => Err(Error::InvalidIsotope(*self, isotope)),
                 },
             },
-            _ => unimplemented!(),
-        }
-    }
-
-    /// Returns the number of valence electrons only for aliphatic/aromatic
-    /// elements under the OpenSMILES specification.
-    pub fn n_valence_electrons(&self, formal_charge: i8) -> Result<u8, Error> {
-        let mut n_valence_electrons = match self {
-            Element::H => 1,
-            Element::B => 3,
-            Element::C => 4,
-            Element::N => 5,
-            Element::O => 6,
-            Element::F => 7,
-            Element::P => 5,
-            Element::S => 6,
-            Element::Cl => 7,
-            Element::Br => 7,
-            Element::I => 7,
-            _ => unimplemented!(),
-        };
-
-        n_valence_electrons -= formal_charge;
-        if !(0..=8).contains(&n_valence_electrons) {
-            return Err(Error::InvalidFormalCharge(
-                self.atomic_symbol(),
-                formal_charge,
-            ));
-        }
-
-        Ok(n_valence_electrons as u8)
-    }
-
-    /// The valence only for aliphatic/aromatic elements under the OpenSMILES
-    /// specification.
-    pub fn valence(&self, formal_charge: i8) -> Result<u8, Error> {
-        let n_valence_electrons = self.n_valence_electrons(formal_charge)?;
-
-        match n_valence_electrons {
-            0 => Ok(0),
-            1 => Ok(1),
-            2 => Ok(2),
-            3 => Ok(3),
-            4 => Ok(4),
-            5 => Ok(3),
-            6 => Ok(2),
-            7 => Ok(1),
-            8 => Ok(0),
-            _ => unreachable!(),
-        }
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
+            Element::K => match isotope {
+                None => Ok(39.0983),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Ca => match isotope {
+                None => Ok(40.078),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Sc => match isotope {
+                None => Ok(44.955_908),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Ti => match isotope {
+                None => Ok(47.867),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::V => match isotope {
+                None => Ok(50.9415),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Cr => match isotope {
+                None => Ok(51.9961),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Mn => match isotope {
+                None => Ok(54.938_044),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Fe => match isotope {
+                None => Ok(55.845),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Co => match isotope {
+                None => Ok(58.933_194),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Ni => match isotope {
+                None => Ok(58.6934),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Cu => match isotope {
+                None => Ok(63.546),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Zn => match isotope {
+                None => Ok(65.38),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Ga => match isotope {
+                None => Ok(69.723),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Ge => match isotope {
+                None => Ok(72.630),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::As => match isotope {
+                None => Ok(74.921_595),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Se => match isotope {
+                None => Ok(78.971),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Kr => match isotope {
+                None => Ok(83.798),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Rb => match isotope {
+                None => Ok(85.4678),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Sr => match isotope {
+                None => Ok(87.62),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Y => match isotope {
+                None => Ok(88.905_84),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Zr => match isotope {
+                None => Ok(91.224),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Nb => match isotope {
+                None => Ok(92.906_37),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Mo => match isotope {
+                None => Ok(95.95),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Tc => match isotope {
+                None => Ok(98.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Ru => match isotope {
+                None => Ok(101.07),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Rh => match isotope {
+                None => Ok(102.905_50),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Pd => match isotope {
+                None => Ok(106.42),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Ag => match isotope {
+                None => Ok(107.8682),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Cd => match isotope {
+                None => Ok(112.414),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::In => match isotope {
+                None => Ok(114.818),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Sn => match isotope {
+                None => Ok(118.710),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Sb => match isotope {
+                None => Ok(121.760),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Te => match isotope {
+                None => Ok(127.60),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Xe => match isotope {
+                None => Ok(131.293),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Cs => match isotope {
+                None => Ok(132.905_452),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Ba => match isotope {
+                None => Ok(137.327),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::La => match isotope {
+                None => Ok(138.905_47),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Ce => match isotope {
+                None => Ok(140.116),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Pr => match isotope {
+                None => Ok(140.907_66),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Nd => match isotope {
+                None => Ok(144.242),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Pm => match isotope {
+                None => Ok(145.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Sm => match isotope {
+                None => Ok(150.36),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Eu => match isotope {
+                None => Ok(151.964),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Gd => match isotope {
+                None => Ok(157.25),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Tb => match isotope {
+                None => Ok(158.925_35),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Dy => match isotope {
+                None => Ok(162.500),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Ho => match isotope {
+                None => Ok(164.930_33),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Er => match isotope {
+                None => Ok(167.259),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Tm => match isotope {
+                None => Ok(168.934_22),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Yb => match isotope {
+                None => Ok(173.054),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Lu => match isotope {
+                None => Ok(174.9668),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Hf => match isotope {
+                None => Ok(178.49),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Ta => match isotope {
+                None => Ok(180.947_88),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::W => match isotope {
+                None => Ok(183.84),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Re => match isotope {
+                None => Ok(186.207),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Os => match isotope {
+                None => Ok(190.23),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Ir => match isotope {
+                None => Ok(192.217),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Pt => match isotope {
+                None => Ok(195.084),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Au => match isotope {
+                None => Ok(196.966_569),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Hg => match isotope {
+                None => Ok(200.592),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Tl => match isotope {
+                None => Ok(204.3835),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Pb => match isotope {
+                None => Ok(207.2),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Bi => match isotope {
+                None => Ok(208.980_40),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Po => match isotope {
+                None => Ok(209.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::At => match isotope {
+                None => Ok(210.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Rn => match isotope {
+                None => Ok(222.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Fr => match isotope {
+                None => Ok(223.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Ra => match isotope {
+                None => Ok(226.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Ac => match isotope {
+                None => Ok(227.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Th => match isotope {
+                None => Ok(232.0377),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Pa => match isotope {
+                None => Ok(231.035_88),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::U => match isotope {
+                None => Ok(238.028_91),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Np => match isotope {
+                None => Ok(237.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Pu => match isotope {
+                None => Ok(244.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Am => match isotope {
+                None => Ok(243.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Cm => match isotope {
+                None => Ok(247.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Bk => match isotope {
+                None => Ok(247.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Cf => match isotope {
+                None => Ok(251.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Es => match isotope {
+                None => Ok(252.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Fm => match isotope {
+                None => Ok(257.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Md => match isotope {
+                None => Ok(258.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::No => match isotope {
+                None => Ok(259.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Lr => match isotope {
+                None => Ok(266.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Rf => match isotope {
+                None => Ok(267.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Db => match isotope {
+                None => Ok(268.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Sg => match isotope {
+                None => Ok(269.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Bh => match isotope {
+                None => Ok(270.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Hs => match isotope {
+                None => Ok(269.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Mt => match isotope {
+                None => Ok(278.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Ds => match isotope {
+                None => Ok(281.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Rg => match isotope {
+                None => Ok(282.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Cn => match isotope {
+                None => Ok(285.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Nh => match isotope {
+                None => Ok(286.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Fl => match isotope {
+                None => Ok(289.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Mc => match isotope {
+                None => Ok(290.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Lv => match isotope {
+                None => Ok(293.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Ts => match isotope {
+                None => Ok(294.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+            Element::Og => match isotope {
+                None => Ok(294.0),
+                Some(isotope) => Err(Error::InvalidIsotope(*self, isotope)),
+            },
+        }
+    }
+
+    /// Returns the number of valence electrons only for aliphatic/aromatic
+    /// elements under the OpenSMILES specification.
+    ///
+    /// Si and Se are supported as well, as an extension beyond the OpenSMILES
+    /// organic subset; use `is_organic_subset` to check for strict OpenSMILES.
+    /// The noble gases have a full valence shell of 8 electrons, or 2 for He.
+    /// Returns `Error::UnsupportedElement` for Any and every other element.
+    pub fn n_valence_electrons(&self, formal_charge: i8) -> Result<u8, Error> {
+        let n_valence_electrons: i16 = match self {
+            Element::H => 1,
+            Element::He => 2,
+            Element::B => 3,
+            Element::C => 4,
+            Element::N => 5,
+            Element::O => 6,
+            Element::F => 7,
+            Element::Ne => 8,
+            Element::Si => 4,
+            Element::P => 5,
+            Element::S => 6,
+            Element::Se => 6,
+            Element::Cl => 7,
+            Element::Br => 7,
+            Element::I => 7,
+            Element::Ar | Element::Kr | Element::Xe | Element::Rn | Element::Og => 8,
+            _ => return Err(Error::UnsupportedElement(*self)),
+        };
+        let shell_capacity = if *self == Element::He { 2 } else { 8 };
+
+        // i16 arithmetic cannot overflow for any i8 formal_charge
+        match u8::try_from(n_valence_electrons - i16::from(formal_charge)) {
+            Ok(n_valence_electrons) if n_valence_electrons <= shell_capacity => {
+                Ok(n_valence_electrons)
+            }
+            _ => Err(Error::InvalidFormalCharge(*self, formal_charge)),
+        }
+    }
+
+    /// The valence only for aliphatic/aromatic elements under the OpenSMILES
+    /// specification, extended to Si (4) and Se (2) like
+    /// `n_valence_electrons`. The noble gases have a valence of 0, ignoring
+    /// exotic compounds such as XeF2.
+    pub fn valence(&self, formal_charge: i8) -> Result<u8, Error> {
+        let n_valence_electrons = self.n_valence_electrons(formal_charge)?;
+
+        // the valence shell of He is already full with 2 electrons
+        if *self == Element::He && n_valence_electrons == 2 {
+            return Ok(0);
+        }
+
+        match n_valence_electrons {
+            0 => Ok(0),
+            1 => Ok(1),
+            2 => Ok(2),
+            3 => Ok(3),
+            4 => Ok(4),
+            5 => Ok(3),
+            6 => Ok(2),
+            7 => Ok(1),
+            8 => Ok(0),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns the van der Waals radius of the Element in picometers, or None
+    /// if no value is available.
+    ///
+    /// Radii are sourced from Bondi (1964), with the missing main group
+    /// elements filled in from Mantina et al. (2009).
+    pub fn van_der_waals_radius(&self) -> Option<f64> {
+        match self {
+            Element::H => Some(120.0),
+            Element::He => Some(140.0),
+            Element::Li => Some(182.0),
+            Element::Be => Some(153.0),
+            Element::B => Some(192.0),
+            Element::C => Some(170.0),
+            Element::N => Some(155.0),
+            Element::O => Some(152.0),
+            Element::F => Some(147.0),
+            Element::Ne => Some(154.0),
+            Element::Na => Some(227.0),
+            Element::Mg => Some(173.0),
+            Element::Al => Some(184.0),
+            Element::Si => Some(210.0),
+            Element::P => Some(180.0),
+            Element::S => Some(180.0),
+            Element::Cl => Some(175.0),
+            Element::Ar => Some(188.0),
+            Element::K => Some(275.0),
+            Element::Ca => Some(231.0),
+            Element::Ni => Some(163.0),
+            Element::Cu => Some(140.0),
+            Element::Zn => Some(139.0),
+            Element::Ga => Some(187.0),
+            Element::Ge => Some(211.0),
+            Element::As => Some(185.0),
+            Element::Se => Some(190.0),
+            Element::Br => Some(185.0),
+            Element::Kr => Some(202.0),
+            Element::Rb => Some(303.0),
+            Element::Sr => Some(249.0),
+            Element::Pd => Some(163.0),
+            Element::Ag => Some(172.0),
+            Element::Cd => Some(158.0),
+            Element::In => Some(193.0),
+            Element::Sn => Some(217.0),
+            Element::Sb => Some(206.0),
+            Element::Te => Some(206.0),
+            Element::I => Some(198.0),
+            Element::Xe => Some(216.0),
+            Element::Cs => Some(343.0),
+            Element::Ba => Some(268.0),
+            Element::Pt => Some(175.0),
+            Element::Au => Some(166.0),
+            Element::Hg => Some(155.0),
+            Element::Tl => Some(196.0),
+            Element::Pb => Some(202.0),
+            Element::Bi => Some(207.0),
+            Element::Po => Some(197.0),
+            Element::At => Some(202.0),
+            Element::Rn => Some(220.0),
+            Element::Fr => Some(348.0),
+            Element::Ra => Some(283.0),
+            Element::U => Some(186.0),
+            _ => None,
+        }
+    }
+
+    /// Returns the charge of the most common monatomic ion of the Element, or
+    /// None if the Element does not commonly form a simple ion (e.g. noble
+    /// gases, carbon).
+    ///
+    /// Where several ions are common a single one is picked: Fe gives +3
+    /// (Fe2+ is also common), Cu gives +2, Tl, Sn and Pb give their lower
+    /// charge, and the lanthanides give +3.
+    pub fn common_ion_charge(&self) -> Option<i8> {
+        match self {
+            Element::H => Some(1),
+            Element::Li => Some(1),
+            Element::Be => Some(2),
+            Element::N => Some(-3),
+            Element::O => Some(-2),
+            Element::F => Some(-1),
+            Element::Na => Some(1),
+            Element::Mg => Some(2),
+            Element::Al => Some(3),
+            Element::P => Some(-3),
+            Element::S => Some(-2),
+            Element::Cl => Some(-1),
+            Element::K => Some(1),
+            Element::Ca => Some(2),
+            Element::Sc => Some(3),
+            Element::Ti => Some(4),
+            Element::V => Some(3),
+            Element::Cr => Some(3),
+            Element::Mn => Some(2),
+            Element::Fe => Some(3),
+            Element::Co => Some(2),
+            Element::Ni => Some(2),
+            Element::Cu => Some(2),
+            Element::Zn => Some(2),
+            Element::Ga => Some(3),
+            Element::Se => Some(-2),
+            Element::Br => Some(-1),
+            Element::Rb => Some(1),
+            Element::Sr => Some(2),
+            Element::Y => Some(3),
+            Element::Zr => Some(4),
+            Element::Pd => Some(2),
+            Element::Ag => Some(1),
+            Element::Cd => Some(2),
+            Element::In => Some(3),
+            Element::Sn => Some(2),
+            Element::Te => Some(-2),
+            Element::I => Some(-1),
+            Element::Cs => Some(1),
+            Element::Ba => Some(2),
+            Element::La => Some(3),
+            Element::Ce => Some(3),
+            Element::Pr => Some(3),
+            Element::Nd => Some(3),
+            Element::Pm => Some(3),
+            Element::Sm => Some(3),
+            Element::Eu => Some(3),
+            Element::Gd => Some(3),
+            Element::Tb => Some(3),
+            Element::Dy => Some(3),
+            Element::Ho => Some(3),
+            Element::Er => Some(3),
+            Element::Tm => Some(3),
+            Element::Yb => Some(3),
+            Element::Lu => Some(3),
+            Element::Pt => Some(2),
+            Element::Au => Some(3),
+            Element::Hg => Some(2),
+            Element::Tl => Some(1),
+            Element::Pb => Some(2),
+            Element::Bi => Some(3),
+            Element::Fr => Some(1),
+            Element::Ra => Some(2),
+            Element::Ac => Some(3),
+            Element::Th => Some(4),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the Element is a metal, i.e. neither a nonmetal nor a
+    /// metalloid.
+    pub fn is_metal(&self) -> bool {
+        match self {
+            Element::Any => false,
+            _ => !self.is_nonmetal() && !self.is_metalloid(),
+        }
+    }
+
+    /// Returns true if the Element is a nonmetal. The halogens and noble gases
+    /// are counted as nonmetals all the way down their groups (At, Rn, Ts and
+    /// Og included).
+    pub fn is_nonmetal(&self) -> bool {
+        matches!(
+            self,
+            Element::H
+                | Element::C
+                | Element::N
+                | Element::O
+                | Element::P
+                | Element::S
+                | Element::Se
+        ) || self.is_halogen()
+            || self.is_noble_gas()
+    }
+
+    /// Returns true if the Element is a metalloid.
+    ///
+    /// The metalloid set varies between references; the conventional set of
+    /// B, Si, Ge, As, Sb, Te and Po is used here.
+    pub fn is_metalloid(&self) -> bool {
+        matches!(
+            self,
+            Element::B
+                | Element::Si
+                | Element::Ge
+                | Element::As
+                | Element::Sb
+                | Element::Te
+                | Element::Po
+        )
+    }
+
+    /// Returns true if the Element is a noble gas (group 18).
+    pub fn is_noble_gas(&self) -> bool {
+        matches!(
+            self,
+            Element::He
+                | Element::Ne
+                | Element::Ar
+                | Element::Kr
+                | Element::Xe
+                | Element::Rn
+                | Element::Og
+        )
+    }
+
+    /// Returns true if the Element is a halogen (group 17).
+    pub fn is_halogen(&self) -> bool {
+        matches!(
+            self,
+            Element::F | Element::Cl | Element::Br | Element::I | Element::At | Element::Ts
+        )
+    }
+
+    /// Returns true if the Element is an alkali metal (group 1). Hydrogen is
+    /// not an alkali metal.
+    pub fn is_alkali_metal(&self) -> bool {
+        matches!(
+            self,
+            Element::Li | Element::Na | Element::K | Element::Rb | Element::Cs | Element::Fr
+        )
+    }
+
+    /// Returns true if the Element is an alkaline earth metal (group 2).
+    pub fn is_alkaline_earth_metal(&self) -> bool {
+        matches!(
+            self,
+            Element::Be | Element::Mg | Element::Ca | Element::Sr | Element::Ba | Element::Ra
+        )
+    }
+
+    /// Returns true if the Element is a transition metal, i.e. in groups 3-12
+    /// excluding the f-block.
+    ///
+    /// La and Ac are counted as a lanthanide and an actinide respectively
+    /// rather than as group 3 transition metals, so group 3 only contributes
+    /// Sc and Y.
+    pub fn is_transition_metal(&self) -> bool {
+        matches!(
+            self.atomic_number(),
+            21..=30 | 39..=48 | 72..=80 | 104..=112
+        )
+    }
+
+    /// Returns true if the Element is a lanthanide (La-Lu, 57-71).
+    pub fn is_lanthanide(&self) -> bool {
+        (57..=71).contains(&self.atomic_number())
+    }
+
+    /// Returns true if the Element is an actinide (Ac-Lr, 89-103).
+    pub fn is_actinide(&self) -> bool {
+        (89..=103).contains(&self.atomic_number())
+    }
+
+    /// Returns the 1-based rank of the Element by abundance (mass fraction) in
+    /// the Earth's crust, e.g. O is 1 and Fe is 4.
+    ///
+    /// Abundances are sourced from the CRC Handbook of Chemistry and Physics.
+    /// Only the 30 most abundant elements are ranked since the order of the
+    /// trace elements differs between sources; all others return None.
+    pub fn crustal_abundance_rank(&self) -> Option<u8> {
+        match self {
+            Element::O => Some(1),
+            Element::Si => Some(2),
+            Element::Al => Some(3),
+            Element::Fe => Some(4),
+            Element::Ca => Some(5),
+            Element::Na => Some(6),
+            Element::Mg => Some(7),
+            Element::K => Some(8),
+            Element::Ti => Some(9),
+            Element::H => Some(10),
+            Element::P => Some(11),
+            Element::Mn => Some(12),
+            Element::F => Some(13),
+            Element::Ba => Some(14),
+            Element::Sr => Some(15),
+            Element::S => Some(16),
+            Element::C => Some(17),
+            Element::Zr => Some(18),
+            Element::Cl => Some(19),
+            Element::V => Some(20),
+            Element::Cr => Some(21),
+            Element::Rb => Some(22),
+            Element::Ni => Some(23),
+            Element::Zn => Some(24),
+            Element::Ce => Some(25),
+            Element::Cu => Some(26),
+            Element::Nd => Some(27),
+            Element::La => Some(28),
+            Element::Y => Some(29),
+            Element::Co => Some(30),
+            _ => None,
+        }
+    }
+
+    /// Returns the StandardState of the Element at 25 °C and 1 atm.
+    ///
+    /// Returns None for Any, for At and Fr which have never been prepared in
+    /// bulk, and for the synthetic elements from Fm onwards whose phase has
+    /// not been measured.
+    pub fn standard_state(&self) -> Option<StandardState> {
+        match self {
+            Element::Any | Element::At | Element::Fr => None,
+            Element::H
+            | Element::N
+            | Element::O
+            | Element::F
+            | Element::Cl
+            | Element::He
+            | Element::Ne
+            | Element::Ar
+            | Element::Kr
+            | Element::Xe
+            | Element::Rn => Some(StandardState::Gas),
+            Element::Br | Element::Hg => Some(StandardState::Liquid),
+            _ if self.atomic_number() >= 100 => None,
+            _ => Some(StandardState::Solid),
+        }
+    }
+
+    /// Returns the melting point of the Element in Kelvin at 1 atm, or None if
+    /// it is unknown or does not exist at that pressure (He, and C and As which
+    /// sublime).
+    ///
+    /// Values are sourced from the CRC Handbook of Chemistry and Physics.
+    pub fn melting_point(&self) -> Option<f64> {
+        match self {
+            Element::H => Some(13.99),
+            Element::Li => Some(453.65),
+            Element::Be => Some(1560.0),
+            Element::B => Some(2349.0),
+            Element::N => Some(63.15),
+            Element::O => Some(54.36),
+            Element::F => Some(53.48),
+            Element::Ne => Some(24.56),
+            Element::Na => Some(370.944),
+            Element::Mg => Some(923.0),
+            Element::Al => Some(933.47),
+            Element::Si => Some(1687.0),
+            Element::P => Some(317.3),
+            Element::S => Some(388.36),
+            Element::Cl => Some(171.6),
+            Element::Ar => Some(83.81),
+            Element::K => Some(336.7),
+            Element::Ca => Some(1115.0),
+            Element::Sc => Some(1814.0),
+            Element::Ti => Some(1941.0),
+            Element::V => Some(2183.0),
+            Element::Cr => Some(2180.0),
+            Element::Mn => Some(1519.0),
+            Element::Fe => Some(1811.0),
+            Element::Co => Some(1768.0),
+            Element::Ni => Some(1728.0),
+            Element::Cu => Some(1357.77),
+            Element::Zn => Some(692.68),
+            Element::Ga => Some(302.9146),
+            Element::Ge => Some(1211.4),
+            Element::Se => Some(494.0),
+            Element::Br => Some(265.8),
+            Element::Kr => Some(115.78),
+            Element::Rb => Some(312.45),
+            Element::Sr => Some(1050.0),
+            Element::Y => Some(1799.0),
+            Element::Zr => Some(2128.0),
+            Element::Nb => Some(2750.0),
+            Element::Mo => Some(2896.0),
+            Element::Tc => Some(2430.0),
+            Element::Ru => Some(2607.0),
+            Element::Rh => Some(2237.0),
+            Element::Pd => Some(1828.05),
+            Element::Ag => Some(1234.93),
+            Element::Cd => Some(594.22),
+            Element::In => Some(429.75),
+            Element::Sn => Some(505.08),
+            Element::Sb => Some(903.78),
+            Element::Te => Some(722.66),
+            Element::I => Some(386.85),
+            Element::Xe => Some(161.4),
+            Element::Cs => Some(301.7),
+            Element::Ba => Some(1000.0),
+            Element::La => Some(1193.0),
+            Element::Ce => Some(1068.0),
+            Element::Pr => Some(1208.0),
+            Element::Nd => Some(1297.0),
+            Element::Pm => Some(1315.0),
+            Element::Sm => Some(1345.0),
+            Element::Eu => Some(1099.0),
+            Element::Gd => Some(1585.0),
+            Element::Tb => Some(1629.0),
+            Element::Dy => Some(1680.0),
+            Element::Ho => Some(1734.0),
+            Element::Er => Some(1802.0),
+            Element::Tm => Some(1818.0),
+            Element::Yb => Some(1097.0),
+            Element::Lu => Some(1925.0),
+            Element::Hf => Some(2506.0),
+            Element::Ta => Some(3290.0),
+            Element::W => Some(3695.0),
+            Element::Re => Some(3459.0),
+            Element::Os => Some(3306.0),
+            Element::Ir => Some(2719.0),
+            Element::Pt => Some(2041.4),
+            Element::Au => Some(1337.33),
+            Element::Hg => Some(234.321),
+            Element::Tl => Some(577.0),
+            Element::Pb => Some(600.61),
+            Element::Bi => Some(544.7),
+            Element::Po => Some(527.0),
+            Element::Rn => Some(202.0),
+            Element::Ra => Some(973.0),
+            Element::Ac => Some(1500.0),
+            Element::Th => Some(2023.0),
+            Element::Pa => Some(1841.0),
+            Element::U => Some(1405.3),
+            Element::Np => Some(912.0),
+            Element::Pu => Some(912.5),
+            Element::Am => Some(1449.0),
+            Element::Cm => Some(1613.0),
+            Element::Bk => Some(1259.0),
+            Element::Cf => Some(1173.0),
+            Element::Es => Some(1133.0),
+            _ => None,
+        }
+    }
+
+    /// Returns the boiling point of the Element in Kelvin at 1 atm, or None if
+    /// it is unknown. For C and As, which sublime, the sublimation point is
+    /// given.
+    ///
+    /// Values are sourced from the CRC Handbook of Chemistry and Physics.
+    pub fn boiling_point(&self) -> Option<f64> {
+        match self {
+            Element::H => Some(20.271),
+            Element::He => Some(4.222),
+            Element::Li => Some(1603.0),
+            Element::Be => Some(2742.0),
+            Element::B => Some(4200.0),
+            Element::C => Some(3915.0),
+            Element::N => Some(77.355),
+            Element::O => Some(90.188),
+            Element::F => Some(85.03),
+            Element::Ne => Some(27.104),
+            Element::Na => Some(1156.09),
+            Element::Mg => Some(1363.0),
+            Element::Al => Some(2743.0),
+            Element::Si => Some(3538.0),
+            Element::P => Some(553.7),
+            Element::S => Some(717.8),
+            Element::Cl => Some(239.11),
+            Element::Ar => Some(87.302),
+            Element::K => Some(1032.0),
+            Element::Ca => Some(1757.0),
+            Element::Sc => Some(3109.0),
+            Element::Ti => Some(3560.0),
+            Element::V => Some(3680.0),
+            Element::Cr => Some(2944.0),
+            Element::Mn => Some(2334.0),
+            Element::Fe => Some(3134.0),
+            Element::Co => Some(3200.0),
+            Element::Ni => Some(3003.0),
+            Element::Cu => Some(2835.0),
+            Element::Zn => Some(1180.0),
+            Element::Ga => Some(2673.0),
+            Element::Ge => Some(3106.0),
+            Element::As => Some(887.0),
+            Element::Se => Some(958.0),
+            Element::Br => Some(332.0),
+            Element::Kr => Some(119.93),
+            Element::Rb => Some(961.0),
+            Element::Sr => Some(1655.0),
+            Element::Y => Some(3609.0),
+            Element::Zr => Some(4682.0),
+            Element::Nb => Some(5017.0),
+            Element::Mo => Some(4912.0),
+            Element::Tc => Some(4538.0),
+            Element::Ru => Some(4423.0),
+            Element::Rh => Some(3968.0),
+            Element::Pd => Some(3236.0),
+            Element::Ag => Some(2435.0),
+            Element::Cd => Some(1040.0),
+            Element::In => Some(2345.0),
+            Element::Sn => Some(2875.0),
+            Element::Sb => Some(1908.0),
+            Element::Te => Some(1261.0),
+            Element::I => Some(457.4),
+            Element::Xe => Some(165.051),
+            Element::Cs => Some(944.0),
+            Element::Ba => Some(2118.0),
+            Element::La => Some(3737.0),
+            Element::Ce => Some(3716.0),
+            Element::Pr => Some(3403.0),
+            Element::Nd => Some(3347.0),
+            Element::Pm => Some(3273.0),
+            Element::Sm => Some(2173.0),
+            Element::Eu => Some(1802.0),
+            Element::Gd => Some(3546.0),
+            Element::Tb => Some(3396.0),
+            Element::Dy => Some(2840.0),
+            Element::Ho => Some(2873.0),
+            Element::Er => Some(3141.0),
+            Element::Tm => Some(2223.0),
+            Element::Yb => Some(1469.0),
+            Element::Lu => Some(3675.0),
+            Element::Hf => Some(4876.0),
+            Element::Ta => Some(5731.0),
+            Element::W => Some(6203.0),
+            Element::Re => Some(5869.0),
+            Element::Os => Some(5285.0),
+            Element::Ir => Some(4403.0),
+            Element::Pt => Some(4098.0),
+            Element::Au => Some(3243.0),
+            Element::Hg => Some(629.88),
+            Element::Tl => Some(1746.0),
+            Element::Pb => Some(2022.0),
+            Element::Bi => Some(1837.0),
+            Element::Po => Some(1235.0),
+            Element::Rn => Some(211.5),
+            Element::Ra => Some(2010.0),
+            Element::Ac => Some(3500.0),
+            Element::Th => Some(5061.0),
+            Element::Pa => Some(4300.0),
+            Element::U => Some(4404.0),
+            Element::Np => Some(4447.0),
+            Element::Pu => Some(3505.0),
+            Element::Am => Some(2880.0),
+            Element::Cm => Some(3383.0),
+            _ => None,
+        }
+    }
+
+    /// Returns how readily the Element forms chains or rings with itself.
+    ///
+    /// Returns None for the metals (other than Sn) and Any, where catenation
+    /// is not a meaningful concept.
+    pub fn catenation_tendency(&self) -> Option<CatenationLevel> {
+        match self {
+            Element::C => Some(CatenationLevel::High),
+            Element::B | Element::Si | Element::P | Element::S | Element::Se | Element::Te => {
+                Some(CatenationLevel::Moderate)
+            }
+            Element::N
+            | Element::O
+            | Element::Ge
+            | Element::As
+            | Element::Sn
+            | Element::Sb
+            | Element::Br
+            | Element::I => Some(CatenationLevel::Low),
+            Element::H | Element::F | Element::Cl => Some(CatenationLevel::None),
+            _ if self.is_noble_gas() => Some(CatenationLevel::None),
+            _ => None,
+        }
+    }
+
+    /// Returns the density of the Element in g/cm³, or None if no reliable
+    /// value is available.
+    ///
+    /// Solids and liquids are given at 20 °C, and gases at 0 °C and 1 atm
+    /// (also in g/cm³, not g/L). Values are sourced from the CRC Handbook of
+    /// Chemistry and Physics.
+    pub fn density(&self) -> Option<f64> {
+        match self {
+            Element::H => Some(0.00008988),
+            Element::He => Some(0.0001785),
+            Element::Li => Some(0.534),
+            Element::Be => Some(1.85),
+            Element::B => Some(2.34),
+            Element::C => Some(2.267),
+            Element::N => Some(0.0012506),
+            Element::O => Some(0.001429),
+            Element::F => Some(0.001696),
+            Element::Ne => Some(0.0009002),
+            Element::Na => Some(0.968),
+            Element::Mg => Some(1.738),
+            Element::Al => Some(2.70),
+            Element::Si => Some(2.329),
+            Element::P => Some(1.823),
+            Element::S => Some(2.07),
+            Element::Cl => Some(0.003214),
+            Element::Ar => Some(0.0017837),
+            Element::K => Some(0.862),
+            Element::Ca => Some(1.55),
+            Element::Sc => Some(2.985),
+            Element::Ti => Some(4.506),
+            Element::V => Some(6.0),
+            Element::Cr => Some(7.19),
+            Element::Mn => Some(7.21),
+            Element::Fe => Some(7.874),
+            Element::Co => Some(8.90),
+            Element::Ni => Some(8.908),
+            Element::Cu => Some(8.96),
+            Element::Zn => Some(7.14),
+            Element::Ga => Some(5.91),
+            Element::Ge => Some(5.323),
+            Element::As => Some(5.727),
+            Element::Se => Some(4.81),
+            Element::Br => Some(3.1028),
+            Element::Kr => Some(0.003749),
+            Element::Rb => Some(1.532),
+            Element::Sr => Some(2.64),
+            Element::Y => Some(4.472),
+            Element::Zr => Some(6.52),
+            Element::Nb => Some(8.57),
+            Element::Mo => Some(10.28),
+            Element::Tc => Some(11.0),
+            Element::Ru => Some(12.45),
+            Element::Rh => Some(12.41),
+            Element::Pd => Some(12.023),
+            Element::Ag => Some(10.49),
+            Element::Cd => Some(8.65),
+            Element::In => Some(7.31),
+            Element::Sn => Some(7.287),
+            Element::Sb => Some(6.697),
+            Element::Te => Some(6.24),
+            Element::I => Some(4.933),
+            Element::Xe => Some(0.005894),
+            Element::Cs => Some(1.93),
+            Element::Ba => Some(3.51),
+            Element::La => Some(6.162),
+            Element::Ce => Some(6.77),
+            Element::Pr => Some(6.77),
+            Element::Nd => Some(7.01),
+            Element::Pm => Some(7.26),
+            Element::Sm => Some(7.52),
+            Element::Eu => Some(5.264),
+            Element::Gd => Some(7.90),
+            Element::Tb => Some(8.23),
+            Element::Dy => Some(8.54),
+            Element::Ho => Some(8.79),
+            Element::Er => Some(9.066),
+            Element::Tm => Some(9.32),
+            Element::Yb => Some(6.90),
+            Element::Lu => Some(9.841),
+            Element::Hf => Some(13.31),
+            Element::Ta => Some(16.69),
+            Element::W => Some(19.25),
+            Element::Re => Some(21.02),
+            Element::Os => Some(22.59),
+            Element::Ir => Some(22.56),
+            Element::Pt => Some(21.45),
+            Element::Au => Some(19.3),
+            Element::Hg => Some(13.534),
+            Element::Tl => Some(11.85),
+            Element::Pb => Some(11.34),
+            Element::Bi => Some(9.78),
+            Element::Po => Some(9.196),
+            Element::Rn => Some(0.00973),
+            Element::Ra => Some(5.5),
+            Element::Ac => Some(10.0),
+            Element::Th => Some(11.7),
+            Element::Pa => Some(15.37),
+            Element::U => Some(19.1),
+            Element::Np => Some(20.45),
+            Element::Pu => Some(19.816),
+            Element::Am => Some(12.0),
+            Element::Cm => Some(13.51),
+            Element::Bk => Some(14.78),
+            Element::Cf => Some(15.1),
+            Element::Es => Some(8.84),
+            _ => None,
+        }
+    }
+
+    /// Returns the country in which the Element was first discovered or
+    /// isolated, using modern country names (e.g. Dubna is given as Russia).
+    ///
+    /// Returns None for Any and for elements known since antiquity or the
+    /// middle ages (C, S, Fe, Cu, Zn, As, Ag, Sn, Sb, Au, Hg, Pb, Bi).
+    /// Where credit is disputed the first accepted claim is given.
+    pub fn discovery_country(&self) -> Option<&'static str> {
+        match self {
+            Element::H => Some("United Kingdom"),
+            Element::He => Some("United Kingdom"),
+            Element::Li => Some("Sweden"),
+            Element::Be => Some("France"),
+            Element::B => Some("France"),
+            Element::N => Some("United Kingdom"),
+            Element::O => Some("Sweden"),
+            Element::F => Some("France"),
+            Element::Ne => Some("United Kingdom"),
+            Element::Na => Some("United Kingdom"),
+            Element::Mg => Some("United Kingdom"),
+            Element::Al => Some("Denmark"),
+            Element::Si => Some("Sweden"),
+            Element::P => Some("Germany"),
+            Element::Cl => Some("Sweden"),
+            Element::Ar => Some("United Kingdom"),
+            Element::K => Some("United Kingdom"),
+            Element::Ca => Some("United Kingdom"),
+            Element::Sc => Some("Sweden"),
+            Element::Ti => Some("United Kingdom"),
+            Element::V => Some("Mexico"),
+            Element::Cr => Some("France"),
+            Element::Mn => Some("Sweden"),
+            Element::Co => Some("Sweden"),
+            Element::Ni => Some("Sweden"),
+            Element::Ga => Some("France"),
+            Element::Ge => Some("Germany"),
+            Element::Se => Some("Sweden"),
+            Element::Br => Some("France"),
+            Element::Kr => Some("United Kingdom"),
+            Element::Rb => Some("Germany"),
+            Element::Sr => Some("United Kingdom"),
+            Element::Y => Some("Finland"),
+            Element::Zr => Some("Germany"),
+            Element::Nb => Some("United Kingdom"),
+            Element::Mo => Some("Sweden"),
+            Element::Tc => Some("Italy"),
+            Element::Ru => Some("Russia"),
+            Element::Rh => Some("United Kingdom"),
+            Element::Pd => Some("United Kingdom"),
+            Element::Cd => Some("Germany"),
+            Element::In => Some("Germany"),
+            Element::Te => Some("Romania"),
+            Element::I => Some("France"),
+            Element::Xe => Some("United Kingdom"),
+            Element::Cs => Some("Germany"),
+            Element::Ba => Some("United Kingdom"),
+            Element::La => Some("Sweden"),
+            Element::Ce => Some("Sweden"),
+            Element::Pr => Some("Austria"),
+            Element::Nd => Some("Austria"),
+            Element::Pm => Some("United States"),
+            Element::Sm => Some("France"),
+            Element::Eu => Some("France"),
+            Element::Gd => Some("Switzerland"),
+            Element::Tb => Some("Sweden"),
+            Element::Dy => Some("France"),
+            Element::Ho => Some("Sweden"),
+            Element::Er => Some("Sweden"),
+            Element::Tm => Some("Sweden"),
+            Element::Yb => Some("Switzerland"),
+            Element::Lu => Some("France"),
+            Element::Hf => Some("Denmark"),
+            Element::Ta => Some("Sweden"),
+            Element::W => Some("Spain"),
+            Element::Re => Some("Germany"),
+            Element::Os => Some("United Kingdom"),
+            Element::Ir => Some("United Kingdom"),
+            Element::Pt => Some("Colombia"),
+            Element::Tl => Some("United Kingdom"),
+            Element::Po => Some("France"),
+            Element::At => Some("United States"),
+            Element::Rn => Some("Germany"),
+            Element::Fr => Some("France"),
+            Element::Ra => Some("France"),
+            Element::Ac => Some("France"),
+            Element::Th => Some("Sweden"),
+            Element::Pa => Some("Germany"),
+            Element::U => Some("Germany"),
+            Element::Np => Some("United States"),
+            Element::Pu => Some("United States"),
+            Element::Am => Some("United States"),
+            Element::Cm => Some("United States"),
+            Element::Bk => Some("United States"),
+            Element::Cf => Some("United States"),
+            Element::Es => Some("United States"),
+            Element::Fm => Some("United States"),
+            Element::Md => Some("United States"),
+            Element::No => Some("Russia"),
+            Element::Lr => Some("United States"),
+            Element::Rf => Some("Russia"),
+            Element::Db => Some("Russia"),
+            Element::Sg => Some("United States"),
+            Element::Bh => Some("Germany"),
+            Element::Hs => Some("Germany"),
+            Element::Mt => Some("Germany"),
+            Element::Ds => Some("Germany"),
+            Element::Rg => Some("Germany"),
+            Element::Cn => Some("Germany"),
+            Element::Nh => Some("Japan"),
+            Element::Fl => Some("Russia"),
+            Element::Mc => Some("Russia"),
+            Element::Lv => Some("Russia"),
+            Element::Ts => Some("Russia"),
+            Element::Og => Some("Russia"),
+            _ => None,
+        }
+    }
+
+    /// Returns the ground-state electron configuration of the neutral Element,
+    /// e.g. "1s2 2s2 2p6 3s2 3p6 3d6 4s2" for Fe, or None for Any.
+    ///
+    /// Subshells are filled in Madelung order, with the well-known anomalies
+    /// (e.g. Cr is 3d5 4s1 and Cu is 3d10 4s1) applied on top. Configurations
+    /// of the superheavy elements are predictions.
+    pub fn electron_configuration(&self) -> Option<String> {
+        let occupancies = self.subshell_occupancies()?;
+        Some(format_subshells(&occupancies))
+    }
+
+    /// Returns the ground-state electron configuration of the neutral Element
+    /// using the preceding noble gas as a core, e.g. "[Ar]3d5 4s1" for Cr, or
+    /// None for Any. H and He have no core and give the full configuration.
+    pub fn electron_configuration_noble_gas_shorthand(&self) -> Option<String> {
+        let mut occupancies = self.subshell_occupancies()?;
+        match self.preceding_noble_gas() {
+            None => Some(format_subshells(&occupancies)),
+            Some(core) => {
+                let core_occupancies = core.subshell_occupancies()?;
+                for (n, core_n) in occupancies.iter_mut().zip(core_occupancies) {
+                    *n -= core_n;
+                }
+                Some(format!("[{core}]{}", format_subshells(&occupancies)))
+            }
+        }
+    }
+
+    /// Returns the number of electrons in each of the SUBSHELLS of the
+    /// neutral Element in its ground state.
+    fn subshell_occupancies(&self) -> Option<[u8; 19]> {
+        if *self == Element::Any {
+            return None;
+        }
+
+        let mut occupancies = [0; 19];
+        let mut n_electrons = self.atomic_number();
+        for (occupancy, (_, l)) in occupancies.iter_mut().zip(SUBSHELLS) {
+            *occupancy = n_electrons.min(4 * l + 2);
+            n_electrons -= *occupancy;
+        }
+
+        // (from, to, n_electrons) moved relative to the Madelung filling
+        let anomaly = match self {
+            Element::Cr | Element::Cu => Some(((4, 0), (3, 2), 1)),
+            Element::Nb | Element::Mo | Element::Ru | Element::Rh | Element::Ag => {
+                Some(((5, 0), (4, 2), 1))
+            }
+            Element::Pd => Some(((5, 0), (4, 2), 2)),
+            Element::La | Element::Ce | Element::Gd => Some(((4, 3), (5, 2), 1)),
+            Element::Pt | Element::Au => Some(((6, 0), (5, 2), 1)),
+            Element::Ac | Element::Pa | Element::U | Element::Np | Element::Cm => {
+                Some(((5, 3), (6, 2), 1))
+            }
+            Element::Th => Some(((5, 3), (6, 2), 2)),
+            Element::Lr => Some(((6, 2), (7, 1), 1)),
+            _ => None,
+        };
+        if let Some((from, to, n)) = anomaly {
+            let index = |subshell| SUBSHELLS.iter().position(|&s| s == subshell).unwrap();
+            occupancies[index(from)] -= n;
+            occupancies[index(to)] += n;
+        }
+
+        Some(occupancies)
+    }
+
+    /// Returns the atomic symbol of the Element padded with trailing spaces to
+    /// two characters, for aligning symbols in monospaced tables.
+    pub fn symbol_padded(&self) -> String {
+        format!("{:<2}", self.symbol())
+    }
+
+    /// Returns the first ionization energy of the Element in kJ/mol, or None
+    /// if it has not been measured.
+    ///
+    /// Values are sourced from the NIST Atomic Spectra Database.
+    pub fn first_ionization_energy(&self) -> Option<f64> {
+        match self {
+            Element::H => Some(1312.0),
+            Element::He => Some(2372.3),
+            Element::Li => Some(520.2),
+            Element::Be => Some(899.5),
+            Element::B => Some(800.6),
+            Element::C => Some(1086.5),
+            Element::N => Some(1402.3),
+            Element::O => Some(1313.9),
+            Element::F => Some(1681.0),
+            Element::Ne => Some(2080.7),
+            Element::Na => Some(495.8),
+            Element::Mg => Some(737.7),
+            Element::Al => Some(577.5),
+            Element::Si => Some(786.5),
+            Element::P => Some(1011.8),
+            Element::S => Some(999.6),
+            Element::Cl => Some(1251.2),
+            Element::Ar => Some(1520.6),
+            Element::K => Some(418.8),
+            Element::Ca => Some(589.8),
+            Element::Sc => Some(633.1),
+            Element::Ti => Some(658.8),
+            Element::V => Some(650.9),
+            Element::Cr => Some(652.9),
+            Element::Mn => Some(717.3),
+            Element::Fe => Some(762.5),
+            Element::Co => Some(760.4),
+            Element::Ni => Some(737.1),
+            Element::Cu => Some(745.5),
+            Element::Zn => Some(906.4),
+            Element::Ga => Some(578.8),
+            Element::Ge => Some(762.0),
+            Element::As => Some(947.0),
+            Element::Se => Some(941.0),
+            Element::Br => Some(1139.9),
+            Element::Kr => Some(1350.8),
+            Element::Rb => Some(403.0),
+            Element::Sr => Some(549.5),
+            Element::Y => Some(600.0),
+            Element::Zr => Some(640.1),
+            Element::Nb => Some(652.1),
+            Element::Mo => Some(684.3),
+            Element::Tc => Some(702.0),
+            Element::Ru => Some(710.2),
+            Element::Rh => Some(719.7),
+            Element::Pd => Some(804.4),
+            Element::Ag => Some(731.0),
+            Element::Cd => Some(867.8),
+            Element::In => Some(558.3),
+            Element::Sn => Some(708.6),
+            Element::Sb => Some(834.0),
+            Element::Te => Some(869.3),
+            Element::I => Some(1008.4),
+            Element::Xe => Some(1170.4),
+            Element::Cs => Some(375.7),
+            Element::Ba => Some(502.9),
+            Element::La => Some(538.1),
+            Element::Ce => Some(534.4),
+            Element::Pr => Some(527.0),
+            Element::Nd => Some(533.1),
+            Element::Pm => Some(540.0),
+            Element::Sm => Some(544.5),
+            Element::Eu => Some(547.1),
+            Element::Gd => Some(593.4),
+            Element::Tb => Some(565.8),
+            Element::Dy => Some(573.0),
+            Element::Ho => Some(581.0),
+            Element::Er => Some(589.3),
+            Element::Tm => Some(596.7),
+            Element::Yb => Some(603.4),
+            Element::Lu => Some(523.5),
+            Element::Hf => Some(658.5),
+            Element::Ta => Some(761.0),
+            Element::W => Some(770.0),
+            Element::Re => Some(760.0),
+            Element::Os => Some(840.0),
+            Element::Ir => Some(880.0),
+            Element::Pt => Some(870.0),
+            Element::Au => Some(890.1),
+            Element::Hg => Some(1007.1),
+            Element::Tl => Some(589.4),
+            Element::Pb => Some(715.6),
+            Element::Bi => Some(703.0),
+            Element::Po => Some(812.1),
+            Element::At => Some(899.0),
+            Element::Rn => Some(1037.0),
+            Element::Fr => Some(393.0),
+            Element::Ra => Some(509.3),
+            Element::Ac => Some(499.0),
+            Element::Th => Some(587.0),
+            Element::Pa => Some(568.0),
+            Element::U => Some(597.6),
+            Element::Np => Some(604.5),
+            Element::Pu => Some(584.7),
+            Element::Am => Some(578.0),
+            Element::Cm => Some(581.0),
+            Element::Bk => Some(601.0),
+            Element::Cf => Some(608.0),
+            Element::Es => Some(619.0),
+            Element::Fm => Some(627.0),
+            Element::Md => Some(635.0),
+            Element::No => Some(642.0),
+            Element::Lr => Some(478.6),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the Element is one of the platinum group metals (Ru,
+    /// Rh, Pd, Os, Ir and Pt).
+    pub fn is_platinum_group(&self) -> bool {
+        matches!(
+            self,
+            Element::Ru | Element::Rh | Element::Pd | Element::Os | Element::Ir | Element::Pt
+        )
+    }
+
+    /// Returns the electron affinity of the Element in kJ/mol, or None if it is
+    /// negative (e.g. noble gases, Be, Mg, N) or has not been measured.
+    ///
+    /// Positive values mean energy is released when the neutral atom gains an
+    /// electron. Note that Ca, Sr and Ba have small but positive affinities.
+    pub fn electron_affinity(&self) -> Option<f64> {
+        match self {
+            Element::H => Some(72.769),
+            Element::Li => Some(59.633),
+            Element::B => Some(26.989),
+            Element::C => Some(121.776),
+            Element::O => Some(140.976),
+            Element::F => Some(328.165),
+            Element::Na => Some(52.867),
+            Element::Al => Some(41.762),
+            Element::Si => Some(134.068),
+            Element::P => Some(72.037),
+            Element::S => Some(200.410),
+            Element::Cl => Some(348.575),
+            Element::K => Some(48.383),
+            Element::Ca => Some(2.37),
+            Element::Sc => Some(18.0),
+            Element::Ti => Some(7.289),
+            Element::V => Some(50.911),
+            Element::Cr => Some(65.21),
+            Element::Fe => Some(14.785),
+            Element::Co => Some(63.898),
+            Element::Ni => Some(111.65),
+            Element::Cu => Some(119.235),
+            Element::Ga => Some(41.0),
+            Element::Ge => Some(118.935),
+            Element::As => Some(77.65),
+            Element::Se => Some(194.958),
+            Element::Br => Some(324.536),
+            Element::Rb => Some(46.884),
+            Element::Sr => Some(5.023),
+            Element::Y => Some(29.6),
+            Element::Zr => Some(41.806),
+            Element::Nb => Some(88.516),
+            Element::Mo => Some(72.1),
+            Element::Tc => Some(53.0),
+            Element::Ru => Some(100.96),
+            Element::Rh => Some(110.27),
+            Element::Pd => Some(54.24),
+            Element::Ag => Some(125.862),
+            Element::In => Some(37.043),
+            Element::Sn => Some(107.298),
+            Element::Sb => Some(101.059),
+            Element::Te => Some(190.161),
+            Element::I => Some(295.153),
+            Element::Cs => Some(45.505),
+            Element::Ba => Some(13.954),
+            Element::La => Some(53.79),
+            Element::Lu => Some(23.04),
+            Element::Hf => Some(17.18),
+            Element::Ta => Some(31.1),
+            Element::W => Some(78.76),
+            Element::Re => Some(5.827),
+            Element::Os => Some(103.99),
+            Element::Ir => Some(150.94),
+            Element::Pt => Some(205.041),
+            Element::Au => Some(222.747),
+            Element::Tl => Some(36.4),
+            Element::Pb => Some(34.42),
+            Element::Bi => Some(90.924),
+            Element::Po => Some(136.0),
+            Element::At => Some(233.087),
+            _ => None,
+        }
+    }
+
+    /// Returns the common oxidation states of the Element in ascending order,
+    /// or an empty slice if it has none (e.g. He, Ne, Ar and Any).
+    ///
+    /// Only the most common states are given, e.g. O is [-2] even though -1
+    /// occurs in peroxides.
+    pub fn common_oxidation_states(&self) -> &'static [i8] {
+        match self {
+            Element::H | Element::At => &[-1, 1],
+            Element::Li
+            | Element::Na
+            | Element::K
+            | Element::Rb
+            | Element::Ag
+            | Element::Cs
+            | Element::Fr => &[1],
+            Element::Be
+            | Element::Mg
+            | Element::Ca
+            | Element::Ni
+            | Element::Zn
+            | Element::Kr
+            | Element::Sr
+            | Element::Cd
+            | Element::Ba
+            | Element::Rn
+            | Element::Ra
+            | Element::No => &[2],
+            Element::B
+            | Element::Al
+            | Element::Sc
+            | Element::Ga
+            | Element::Y
+            | Element::Rh
+            | Element::In
+            | Element::La
+            | Element::Pr
+            | Element::Nd
+            | Element::Pm
+            | Element::Sm
+            | Element::Gd
+            | Element::Tb
+            | Element::Dy
+            | Element::Ho
+            | Element::Er
+            | Element::Tm
+            | Element::Yb
+            | Element::Lu
+            | Element::Au
+            | Element::Bi
+            | Element::Ac
+            | Element::Am
+            | Element::Cm
+            | Element::Bk
+            | Element::Cf
+            | Element::Es
+            | Element::Fm
+            | Element::Md
+            | Element::Lr => &[3],
+            Element::C | Element::Si => &[-4, 4],
+            Element::N | Element::P | Element::As | Element::Sb => &[-3, 3, 5],
+            Element::O => &[-2],
+            Element::F => &[-1],
+            Element::S | Element::Se | Element::Te => &[-2, 2, 4, 6],
+            Element::Cl | Element::I => &[-1, 1, 3, 5, 7],
+            Element::Ti
+            | Element::Zr
+            | Element::Hf
+            | Element::Re
+            | Element::Os
+            | Element::Th
+            | Element::Pu
+            | Element::Rf => &[4],
+            Element::V | Element::Nb | Element::Ta | Element::Pa | Element::Np | Element::Db => {
+                &[5]
+            }
+            Element::Cr => &[3, 6],
+            Element::Mn => &[2, 4, 7],
+            Element::Fe | Element::Co | Element::Eu => &[2, 3],
+            Element::Cu | Element::Hg => &[1, 2],
+            Element::Ge | Element::Sn => &[-4, 2, 4],
+            Element::Br => &[-1, 1, 3, 5],
+            Element::Mo | Element::W => &[4, 6],
+            Element::Tc => &[4, 7],
+            Element::Ru | Element::Ce | Element::Ir => &[3, 4],
+            Element::Pd | Element::Pt | Element::Pb => &[2, 4],
+            Element::Xe => &[2, 4, 6],
+            Element::Tl => &[1, 3],
+            Element::Po => &[-2, 2, 4],
+            Element::U | Element::Sg => &[6],
+            Element::Bh => &[7],
+            Element::Hs => &[8],
+            _ => &[],
+        }
+    }
+
+    /// Returns the formula of the typical oxide of the Element, e.g. "Na2O",
+    /// "Al2O3" or "SO3", derived from its highest common oxidation state.
+    ///
+    /// Returns None for O itself, for the noble gases and for elements with no
+    /// common positive oxidation state (e.g. F).
+    pub fn typical_oxide_formula(&self) -> Option<String> {
+        if *self == Element::O || self.is_noble_gas() {
+            return None;
+        }
+
+        let oxidation_state = *self.common_oxidation_states().last()?;
+        if oxidation_state <= 0 {
+            return None;
+        }
+
+        Some(binary_formula(*self, oxidation_state as u8, Element::O, 2))
+    }
+
+    /// All the valences only for aliphatic/aromatic elements under the
+    /// OpenSMILES specification, in ascending order.
+    ///
+    /// Neutral N and P may be 3 or 5, and neutral S and Se may be 2, 4 or 6.
+    /// Every other case has the single valence given by `valence`. Si and Se
+    /// are an extension beyond the OpenSMILES organic subset, see
+    /// `n_valence_electrons`. Other elements give an
+    /// `Error::UnsupportedElement`.
+    pub fn valences(&self, formal_charge: i8) -> Result<&'static [u8], Error> {
+        match (self, formal_charge) {
+            (Element::N | Element::P, 0) => Ok(&[3, 5]),
+            (Element::S | Element::Se, 0) => Ok(&[2, 4, 6]),
+            _ => match self.valence(formal_charge)? {
+                0 => Ok(&[0]),
+                1 => Ok(&[1]),
+                2 => Ok(&[2]),
+                3 => Ok(&[3]),
+                4 => Ok(&[4]),
+                _ => unreachable!(),
+            },
+        }
+    }
+
+    /// Returns the monoisotopic mass of the Element, i.e. the exact mass of its
+    /// most abundant isotope, as used in mass spectrometry.
+    ///
+    /// Every element with a stable isotope is supported, see
+    /// `Element::has_stable_isotope`. Elements covered by
+    /// `Element::isotope_abundances` reuse the isotope masses of
+    /// `Element::atomic_weight`, the rest use masses from AME2020. Elements
+    /// without a stable isotope give an `Error::UnsupportedElement`.
+    pub fn monoisotopic_mass(&self) -> Result<f64, Error> {
+        match self {
+            Element::Any => Ok(0.0),
+            _ => match self.most_abundant_isotope() {
+                Some(isotope) => self.atomic_weight(Some(isotope)),
+                None => match self {
+                    Element::K => Ok(38.963_706),
+                    Element::Ca => Ok(39.962_591),
+                    Element::Sc => Ok(44.955_908),
+                    Element::Ti => Ok(47.947_941),
+                    Element::V => Ok(50.943_957),
+                    Element::Cr => Ok(51.940_505),
+                    Element::Mn => Ok(54.938_043),
+                    Element::Fe => Ok(55.934_936),
+                    Element::Co => Ok(58.933_194),
+                    Element::Ni => Ok(57.935_342),
+                    Element::Cu => Ok(62.929_597),
+                    Element::Zn => Ok(63.929_142),
+                    Element::Ga => Ok(68.925_573),
+                    Element::Ge => Ok(73.921_177),
+                    Element::As => Ok(74.921_595),
+                    Element::Se => Ok(79.916_521),
+                    Element::Kr => Ok(83.911_498),
+                    Element::Rb => Ok(84.911_79),
+                    Element::Sr => Ok(87.905_612),
+                    Element::Y => Ok(88.905_838),
+                    Element::Zr => Ok(89.904_697),
+                    Element::Nb => Ok(92.906_373),
+                    Element::Mo => Ok(97.905_404),
+                    Element::Ru => Ok(101.904_344),
+                    Element::Rh => Ok(102.905_498),
+                    Element::Pd => Ok(105.903_48),
+                    Element::Ag => Ok(106.905_092),
+                    Element::Cd => Ok(113.903_365),
+                    Element::In => Ok(114.903_879),
+                    Element::Sn => Ok(119.902_202),
+                    Element::Sb => Ok(120.903_812),
+                    Element::Te => Ok(129.906_223),
+                    Element::Xe => Ok(131.904_155),
+                    Element::Cs => Ok(132.905_452),
+                    Element::Ba => Ok(137.905_247),
+                    Element::La => Ok(138.906_363),
+                    Element::Ce => Ok(139.905_449),
+                    Element::Pr => Ok(140.907_66),
+                    Element::Nd => Ok(141.907_729),
+                    Element::Sm => Ok(151.919_74),
+                    Element::Eu => Ok(152.921_238),
+                    Element::Gd => Ok(157.924_112),
+                    Element::Tb => Ok(158.925_355),
+                    Element::Dy => Ok(163.929_182),
+                    Element::Ho => Ok(164.930_329),
+                    Element::Er => Ok(165.930_301),
+                    Element::Tm => Ok(168.934_219),
+                    Element::Yb => Ok(173.938_868),
+                    Element::Lu => Ok(174.940_777),
+                    Element::Hf => Ok(179.946_557),
+                    Element::Ta => Ok(180.947_999),
+                    Element::W => Ok(183.950_933),
+                    Element::Re => Ok(186.955_752),
+                    Element::Os => Ok(191.961_477),
+                    Element::Ir => Ok(192.962_924),
+                    Element::Pt => Ok(194.964_794),
+                    Element::Au => Ok(196.966_57),
+                    Element::Hg => Ok(201.970_644),
+                    Element::Tl => Ok(204.974_427),
+                    Element::Pb => Ok(207.976_652),
+                    _ => Err(Error::UnsupportedElement(*self)),
+                },
+            },
+        }
+    }
+
+    /// Returns the natural abundances of the stable isotopes of the Element as
+    /// (mass number, fraction) pairs summing to 1.0, or an empty slice if no
+    /// data is available.
+    ///
+    /// Only elements with isotope data in `atomic_weight` are covered. Values
+    /// are the IUPAC representative isotopic compositions.
+    pub fn isotope_abundances(&self) -> &'static [(u16, f64)] {
+        match self {
+            Element::H => &[(1, 0.999885), (2, 0.000115)],
+            Element::He => &[(3, 0.00000134), (4, 0.99999866)],
+            Element::Li => &[(6, 0.0759), (7, 0.9241)],
+            Element::Be => &[(9, 1.0)],
+            Element::B => &[(10, 0.199), (11, 0.801)],
+            Element::C => &[(12, 0.9893), (13, 0.0107)],
+            Element::N => &[(14, 0.99636), (15, 0.00364)],
+            Element::O => &[(16, 0.99757), (17, 0.00038), (18, 0.00205)],
+            Element::F => &[(19, 1.0)],
+            Element::Ne => &[(20, 0.9048), (21, 0.0027), (22, 0.0925)],
+            Element::Na => &[(23, 1.0)],
+            Element::Mg => &[(24, 0.7899), (25, 0.1), (26, 0.1101)],
+            Element::Al => &[(27, 1.0)],
+            Element::Si => &[(28, 0.92223), (29, 0.04685), (30, 0.03092)],
+            Element::P => &[(31, 1.0)],
+            Element::S => &[(32, 0.9499), (33, 0.0075), (34, 0.0425), (36, 0.0001)],
+            Element::Cl => &[(35, 0.7576), (37, 0.2424)],
+            Element::Ar => &[(36, 0.003336), (38, 0.000629), (40, 0.996035)],
+            Element::Br => &[(79, 0.5069), (81, 0.4931)],
+            Element::I => &[(127, 1.0)],
+            _ => &[],
+        }
+    }
+
+    /// Returns the mass number of the most abundant isotope of the Element, or
+    /// None if no abundance data is available.
+    pub fn most_abundant_isotope(&self) -> Option<u16> {
+        self.isotope_abundances()
+            .iter()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|&(isotope, _)| isotope)
+    }
+
+    /// Returns the SimpleColorClass of the Element for printed tables using a
+    /// reduced four-color scheme.
+    ///
+    /// Any and the superheavy elements from Mt onwards, whose chemical
+    /// properties are unknown, are `SimpleColorClass::Unknown`.
+    pub fn simple_color_class(&self) -> SimpleColorClass {
+        if *self == Element::Any || self.atomic_number() >= 109 {
+            SimpleColorClass::Unknown
+        } else if self.is_metalloid() {
+            SimpleColorClass::Metalloid
+        } else if self.is_nonmetal() {
+            SimpleColorClass::Nonmetal
+        } else {
+            SimpleColorClass::Metal
+        }
+    }
+
+    /// Returns the atomic number of the Element formatted in the given radix,
+    /// using lowercase letters for digits above 9, e.g. "110" for C in base 2.
+    ///
+    /// # Panics
+    ///
+    /// Panics if radix is not in the range 2 to 36.
+    pub fn atomic_number_radix(&self, radix: u32) -> String {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be in the range 2 to 36, got {radix}"
+        );
+
+        let mut n = u32::from(self.atomic_number());
+        let mut digits = vec![];
+        loop {
+            digits.push(char::from_digit(n % radix, radix).unwrap());
+            n /= radix;
+            if n == 0 {
+                break;
+            }
+        }
+        digits.iter().rev().collect()
+    }
+
+    /// Returns the nearest noble gas with a lower atomic number than the
+    /// Element, e.g. Ne for Na, or None for Any, H and He.
+    pub fn preceding_noble_gas(&self) -> Option<Element> {
+        [
+            Element::Rn,
+            Element::Xe,
+            Element::Kr,
+            Element::Ar,
+            Element::Ne,
+            Element::He,
+        ]
+        .into_iter()
+        .find(|noble_gas| noble_gas.atomic_number() < self.atomic_number())
+    }
+
+    /// Returns true if the standard atomic weight of the Element is within ppm
+    /// parts per million of reference, for cross-checking against external
+    /// datasets.
+    pub fn weight_matches(&self, reference: f64, ppm: f64) -> Result<bool, Error> {
+        let weight = self.atomic_weight(None)?;
+        Ok((weight - reference).abs() <= reference.abs() * ppm * 1e-6)
+    }
+
+    /// Parses an atomic symbol as written in SMILES, returning the Element and
+    /// whether it is aromatic.
+    ///
+    /// Unlike `FromStr`, this is case-sensitive: symbols must either use their
+    /// canonical casing (aliphatic) or be one of the lowercase aromatic symbols
+    /// b, c, n, o, p, s, se and as.
+    pub fn from_smiles_symbol(s: &str) -> Result<(Element, bool), Error> {
+        match s.parse::<Element>() {
+            Ok(element) if element.symbol() == s => Ok((element, false)),
+            Ok(element)
+                if element.is_aromatic_organic_subset()
+                    && s.chars().all(|c| c.is_ascii_lowercase()) =>
+            {
+                Ok((element, true))
+            }
+            _ => Err(Error::InvalidAtomicSymbol(s.to_owned())),
+        }
+    }
+
+    /// Returns the orbital hybridizations the Element commonly adopts, e.g.
+    /// ["sp", "sp2", "sp3"] for C, or an empty slice where hybridization is
+    /// not a meaningful description of its bonding.
+    pub fn common_hybridizations(&self) -> &'static [&'static str] {
+        match self {
+            Element::Be => &["sp"],
+            Element::B | Element::O => &["sp2", "sp3"],
+            Element::C | Element::N => &["sp", "sp2", "sp3"],
+            Element::Si => &["sp3"],
+            Element::P => &["sp3", "sp3d"],
+            Element::S => &["sp3", "sp3d", "sp3d2"],
+            _ => &[],
+        }
+    }
+
+    /// Returns true if the Element may be written as a lowercase aromatic
+    /// atom in SMILES (B, C, N, O, P, S, Se and As).
+    pub fn is_aromatic_organic_subset(&self) -> bool {
+        matches!(
+            self,
+            Element::B
+                | Element::C
+                | Element::N
+                | Element::O
+                | Element::P
+                | Element::S
+                | Element::Se
+                | Element::As
+        )
+    }
+
+    /// Returns true if the Element belongs to the OpenSMILES organic subset
+    /// (B, C, N, O, P, S, F, Cl, Br and I) and may be written without
+    /// brackets.
+    pub fn is_organic_subset(&self) -> bool {
+        matches!(
+            self,
+            Element::B
+                | Element::C
+                | Element::N
+                | Element::O
+                | Element::P
+                | Element::S
+                | Element::F
+                | Element::Cl
+                | Element::Br
+                | Element::I
+        )
+    }
+
+    /// Returns the mass numbers of the isotopes supported by
+    /// `Element::atomic_weight`, or an empty slice if none are.
+    ///
+    /// Any accepts every isotope and therefore also returns an empty slice.
+    pub fn known_isotopes(&self) -> &'static [u16] {
+        match self {
+            Element::H => &[1, 2, 3],
+            Element::He => &[3, 4],
+            Element::Li => &[6, 7],
+            Element::Be => &[9],
+            Element::B => &[10, 11],
+            Element::C => &[12, 13, 14],
+            Element::N => &[14, 15],
+            Element::O => &[16, 17, 18],
+            Element::F => &[19],
+            Element::Ne => &[20, 21, 22],
+            Element::Na => &[23],
+            Element::Mg => &[24, 25, 26],
+            Element::Al => &[27],
+            Element::Si => &[28, 29, 30],
+            Element::P => &[31],
+            Element::S => &[32, 33, 34, 36],
+            Element::Cl => &[35, 37],
+            Element::Ar => &[36, 38, 40],
+            Element::Br => &[79, 81],
+            Element::I => &[127],
+            _ => &[],
+        }
+    }
+
+    /// Returns the RedoxTendency of the Element in its elemental form, based
+    /// on its electronegativity and standard reduction potential.
+    ///
+    /// F, O and Cl are strong oxidizers and the other electronegative
+    /// nonmetals are oxidizers. The alkali metals and the alkaline earth
+    /// metals from Mg onwards (E° below about -2.3 V) are strong reducers and
+    /// the remaining elements are reducers. Returns None for Any, for the
+    /// noble gases and for the elements from Rf onwards whose redox chemistry
+    /// is unknown.
+    pub fn redox_tendency(&self) -> Option<RedoxTendency> {
+        if *self == Element::Any || self.is_noble_gas() || self.atomic_number() >= 104 {
+            return None;
+        }
+
+        match self {
+            Element::F | Element::O | Element::Cl => Some(RedoxTendency::StrongOxidizer),
+            Element::N | Element::S | Element::Se | Element::Br | Element::I | Element::At => {
+                Some(RedoxTendency::Oxidizer)
+            }
+            _ if self.is_alkali_metal() => Some(RedoxTendency::StrongReducer),
+            _ if self.is_alkaline_earth_metal() && *self != Element::Be => {
+                Some(RedoxTendency::StrongReducer)
+            }
+            _ => Some(RedoxTendency::Reducer),
+        }
+    }
+
+    /// Returns the CPK color of the Element as an RGB triple for molecular
+    /// visualization.
+    ///
+    /// Colors follow the Jmol palette, which assigns colors up to Mt. Any and
+    /// the elements after Mt get the "unknown element" pink [255, 20, 147].
+    pub fn cpk_color(&self) -> [u8; 3] {
+        match self {
+            Element::H => [255, 255, 255],
+            Element::He => [217, 255, 255],
+            Element::Li => [204, 128, 255],
+            Element::Be => [194, 255, 0],
+            Element::B => [255, 181, 181],
+            Element::C => [144, 144, 144],
+            Element::N => [48, 80, 248],
+            Element::O => [255, 13, 13],
+            Element::F => [144, 224, 80],
+            Element::Ne => [179, 227, 245],
+            Element::Na => [171, 92, 242],
+            Element::Mg => [138, 255, 0],
+            Element::Al => [191, 166, 166],
+            Element::Si => [240, 200, 160],
+            Element::P => [255, 128, 0],
+            Element::S => [255, 255, 48],
+            Element::Cl => [31, 240, 31],
+            Element::Ar => [128, 209, 227],
+            Element::K => [143, 64, 212],
+            Element::Ca => [61, 255, 0],
+            Element::Sc => [230, 230, 230],
+            Element::Ti => [191, 194, 199],
+            Element::V => [166, 166, 171],
+            Element::Cr => [138, 153, 199],
+            Element::Mn => [156, 122, 199],
+            Element::Fe => [224, 102, 51],
+            Element::Co => [240, 144, 160],
+            Element::Ni => [80, 208, 80],
+            Element::Cu => [200, 128, 51],
+            Element::Zn => [125, 128, 176],
+            Element::Ga => [194, 143, 143],
+            Element::Ge => [102, 143, 143],
+            Element::As => [189, 128, 227],
+            Element::Se => [255, 161, 0],
+            Element::Br => [166, 41, 41],
+            Element::Kr => [92, 184, 209],
+            Element::Rb => [112, 46, 176],
+            Element::Sr => [0, 255, 0],
+            Element::Y => [148, 255, 255],
+            Element::Zr => [148, 224, 224],
+            Element::Nb => [115, 194, 201],
+            Element::Mo => [84, 181, 181],
+            Element::Tc => [59, 158, 158],
+            Element::Ru => [36, 143, 143],
+            Element::Rh => [10, 125, 140],
+            Element::Pd => [0, 105, 133],
+            Element::Ag => [192, 192, 192],
+            Element::Cd => [255, 217, 143],
+            Element::In => [166, 117, 115],
+            Element::Sn => [102, 128, 128],
+            Element::Sb => [158, 99, 181],
+            Element::Te => [212, 122, 0],
+            Element::I => [148, 0, 148],
+            Element::Xe => [66, 158, 176],
+            Element::Cs => [87, 23, 143],
+            Element::Ba => [0, 201, 0],
+            Element::La => [112, 212, 255],
+            Element::Ce => [255, 255, 199],
+            Element::Pr => [217, 255, 199],
+            Element::Nd => [199, 255, 199],
+            Element::Pm => [163, 255, 199],
+            Element::Sm => [143, 255, 199],
+            Element::Eu => [97, 255, 199],
+            Element::Gd => [69, 255, 199],
+            Element::Tb => [48, 255, 199],
+            Element::Dy => [31, 255, 199],
+            Element::Ho => [0, 255, 156],
+            Element::Er => [0, 230, 117],
+            Element::Tm => [0, 212, 82],
+            Element::Yb => [0, 191, 56],
+            Element::Lu => [0, 171, 36],
+            Element::Hf => [77, 194, 255],
+            Element::Ta => [77, 166, 255],
+            Element::W => [33, 148, 214],
+            Element::Re => [38, 125, 171],
+            Element::Os => [38, 102, 150],
+            Element::Ir => [23, 84, 135],
+            Element::Pt => [208, 208, 224],
+            Element::Au => [255, 209, 35],
+            Element::Hg => [184, 184, 208],
+            Element::Tl => [166, 84, 77],
+            Element::Pb => [87, 89, 97],
+            Element::Bi => [158, 79, 181],
+            Element::Po => [171, 92, 0],
+            Element::At => [117, 79, 69],
+            Element::Rn => [66, 130, 150],
+            Element::Fr => [66, 0, 102],
+            Element::Ra => [0, 125, 0],
+            Element::Ac => [112, 171, 250],
+            Element::Th => [0, 186, 255],
+            Element::Pa => [0, 161, 255],
+            Element::U => [0, 143, 255],
+            Element::Np => [0, 128, 255],
+            Element::Pu => [0, 107, 255],
+            Element::Am => [84, 92, 242],
+            Element::Cm => [120, 92, 227],
+            Element::Bk => [138, 79, 227],
+            Element::Cf => [161, 54, 212],
+            Element::Es => [179, 31, 212],
+            Element::Fm => [179, 31, 186],
+            Element::Md => [179, 13, 166],
+            Element::No => [189, 13, 135],
+            Element::Lr => [199, 0, 102],
+            Element::Rf => [204, 0, 89],
+            Element::Db => [209, 0, 79],
+            Element::Sg => [217, 0, 69],
+            Element::Bh => [224, 0, 56],
+            Element::Hs => [230, 0, 46],
+            Element::Mt => [235, 0, 38],
+            _ => [255, 20, 147],
+        }
+    }
+
+    /// Returns the principal decay mode of the longest-lived isotope of the
+    /// Element ("alpha", "beta-", "electron capture" or "spontaneous
+    /// fission"), or None if the Element has a stable isotope.
+    ///
+    /// The isotope considered is the one whose mass number is given by
+    /// `Element::atomic_weight` for elements without a standard atomic weight,
+    /// e.g. Tc-98 for Tc. Bi is treated as radioactive since Bi-209 undergoes
+    /// alpha decay, albeit with a half-life of about 2e19 years.
+    pub fn principal_decay_mode(&self) -> Option<&'static str> {
+        match self {
+            Element::Tc | Element::Fr | Element::Ac => Some("beta-"),
+            Element::Pm | Element::At => Some("electron capture"),
+            Element::Lr | Element::Rf | Element::Db | Element::Ds => Some("spontaneous fission"),
+            _ if self.atomic_number() >= 83 => Some("alpha"),
+            _ => None,
+        }
+    }
+
+    /// Returns the principal quantum number of the outermost occupied shell
+    /// of the neutral Element in its ground state, or None for Any.
+    ///
+    /// This equals the period for main-group elements. For the d- and f-block
+    /// elements the outermost shell is still the ns shell, as the (n-1)d and
+    /// (n-2)f subshells are inner shells, so the period is returned as well.
+    /// The one exception is Pd ([Kr] 4d10), whose outermost shell is n = 4.
+    pub fn valence_shell_n(&self) -> Option<u8> {
+        let occupancies = self.subshell_occupancies()?;
+        SUBSHELLS
+            .iter()
+            .zip(occupancies)
+            .filter(|&(_, occupancy)| occupancy > 0)
+            .map(|(&(n, _), _)| n)
+            .max()
+    }
+
+    /// Returns the period (row) of the Element in the periodic table, or None
+    /// for Any.
+    pub fn period(&self) -> Option<u8> {
+        match self.atomic_number() {
+            0 => None,
+            1..=2 => Some(1),
+            3..=10 => Some(2),
+            11..=18 => Some(3),
+            19..=36 => Some(4),
+            37..=54 => Some(5),
+            55..=86 => Some(6),
+            _ => Some(7),
+        }
+    }
+
+    /// Returns the IUPAC group (1-18) of the Element in the periodic table.
+    ///
+    /// Returns None for Any and for the f-block, i.e. the lanthanides and
+    /// actinides (see `Element::is_lanthanide` and `Element::is_actinide`).
+    pub fn group(&self) -> Option<u8> {
+        let n = self.atomic_number();
+        match n {
+            1 => Some(1),
+            2 => Some(18),
+            3..=4 => Some(n - 2),
+            5..=10 => Some(n + 8),
+            11..=12 => Some(n - 10),
+            13..=18 => Some(n),
+            19..=36 => Some(n - 18),
+            37..=54 => Some(n - 36),
+            55..=56 => Some(n - 54),
+            72..=86 => Some(n - 68),
+            87..=88 => Some(n - 86),
+            104..=118 => Some(n - 100),
+            _ => None,
+        }
+    }
+
+    /// Returns (group, period) as a sort key for traversing the periodic table
+    /// column by column, i.e. down group 1, then down group 2 and so on.
+    ///
+    /// Returns None for Any and for the f-block, which have no group.
+    pub fn column_major_key(&self) -> Option<(u8, u8)> {
+        Some((self.group()?, self.period()?))
+    }
+
+    /// Returns the Mendeleev number of the Element on Pettifor's chemical
+    /// scale (D. G. Pettifor, J. Phys. C: Solid State Phys. 19, 285 (1986)),
+    /// a 1D ordering from He (1) to H (103) in which chemically similar
+    /// elements are neighbors.
+    ///
+    /// Returns None for Any and for the elements after Lr, which are not on
+    /// the scale.
+    pub fn mendeleev_number(&self) -> Option<u8> {
+        match self {
+            Element::He => Some(1),
+            Element::Ne => Some(2),
+            Element::Ar => Some(3),
+            Element::Kr => Some(4),
+            Element::Xe => Some(5),
+            Element::Rn => Some(6),
+            Element::Fr => Some(7),
+            Element::Cs => Some(8),
+            Element::Rb => Some(9),
+            Element::K => Some(10),
+            Element::Na => Some(11),
+            Element::Li => Some(12),
+            Element::Ra => Some(13),
+            Element::Ba => Some(14),
+            Element::Sr => Some(15),
+            Element::Ca => Some(16),
+            Element::Yb => Some(17),
+            Element::Eu => Some(18),
+            Element::Y => Some(19),
+            Element::Sc => Some(20),
+            Element::Lu => Some(21),
+            Element::Tm => Some(22),
+            Element::Er => Some(23),
+            Element::Ho => Some(24),
+            Element::Dy => Some(25),
+            Element::Tb => Some(26),
+            Element::Gd => Some(27),
+            Element::Sm => Some(28),
+            Element::Pm => Some(29),
+            Element::Nd => Some(30),
+            Element::Pr => Some(31),
+            Element::Ce => Some(32),
+            Element::La => Some(33),
+            Element::Lr => Some(34),
+            Element::No => Some(35),
+            Element::Md => Some(36),
+            Element::Fm => Some(37),
+            Element::Es => Some(38),
+            Element::Cf => Some(39),
+            Element::Bk => Some(40),
+            Element::Cm => Some(41),
+            Element::Am => Some(42),
+            Element::Pu => Some(43),
+            Element::Np => Some(44),
+            Element::U => Some(45),
+            Element::Pa => Some(46),
+            Element::Th => Some(47),
+            Element::Ac => Some(48),
+            Element::Zr => Some(49),
+            Element::Hf => Some(50),
+            Element::Ti => Some(51),
+            Element::Nb => Some(52),
+            Element::Ta => Some(53),
+            Element::V => Some(54),
+            Element::Mo => Some(55),
+            Element::W => Some(56),
+            Element::Cr => Some(57),
+            Element::Tc => Some(58),
+            Element::Re => Some(59),
+            Element::Mn => Some(60),
+            Element::Fe => Some(61),
+            Element::Os => Some(62),
+            Element::Ru => Some(63),
+            Element::Co => Some(64),
+            Element::Ir => Some(65),
+            Element::Rh => Some(66),
+            Element::Ni => Some(67),
+            Element::Pt => Some(68),
+            Element::Pd => Some(69),
+            Element::Au => Some(70),
+            Element::Ag => Some(71),
+            Element::Cu => Some(72),
+            Element::Mg => Some(73),
+            Element::Hg => Some(74),
+            Element::Cd => Some(75),
+            Element::Zn => Some(76),
+            Element::Be => Some(77),
+            Element::Tl => Some(78),
+            Element::In => Some(79),
+            Element::Al => Some(80),
+            Element::Ga => Some(81),
+            Element::Pb => Some(82),
+            Element::Sn => Some(83),
+            Element::Ge => Some(84),
+            Element::Si => Some(85),
+            Element::B => Some(86),
+            Element::Bi => Some(87),
+            Element::Sb => Some(88),
+            Element::As => Some(89),
+            Element::P => Some(90),
+            Element::Po => Some(91),
+            Element::Te => Some(92),
+            Element::Se => Some(93),
+            Element::S => Some(94),
+            Element::C => Some(95),
+            Element::At => Some(96),
+            Element::I => Some(97),
+            Element::Br => Some(98),
+            Element::Cl => Some(99),
+            Element::N => Some(100),
+            Element::O => Some(101),
+            Element::F => Some(102),
+            Element::H => Some(103),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the Elements are the same or if either of them is Any,
+    /// i.e. the query-matching semantics of Any as a wildcard. Use `==` for
+    /// strict equality.
+    pub fn matches(&self, other: &Element) -> bool {
+        *self == Element::Any || *other == Element::Any || self == other
+    }
+
+    /// Returns the standard uncertainty of the value given by
+    /// `Element::atomic_weight` for the same isotope.
+    ///
+    /// For standard atomic weights this is the CIAAW 2013 uncertainty, or
+    /// half the width of the interval for elements whose standard atomic
+    /// weight is given as an interval, e.g. 0.0085 for S [32.059, 32.076].
+    /// Isotope masses are tabulated to six decimal places and are given an
+    /// uncertainty of half of the last digit. Returns 0.0 for Any.
+    ///
+    /// Returns `Error::UnsupportedElement` for elements without a standard
+    /// atomic weight, whose `atomic_weight` is a mass number.
+    pub fn atomic_weight_uncertainty(&self, isotope: Option<u16>) -> Result<f64, Error> {
+        self.atomic_weight(isotope)?;
+        if *self == Element::Any {
+            return Ok(0.0);
+        }
+        if isotope.is_some() {
+            return Ok(0.000_000_5);
+        }
+
+        match self {
+            Element::H => Ok(0.000_135),
+            Element::He => Ok(0.000_002),
+            Element::Li => Ok(0.029_5),
+            Element::Be => Ok(0.000_000_5),
+            Element::B => Ok(0.007_5),
+            Element::C => Ok(0.001),
+            Element::N => Ok(0.000_425),
+            Element::O => Ok(0.000_37),
+            Element::F => Ok(0.000_000_006),
+            Element::Ne => Ok(0.000_6),
+            Element::Na => Ok(0.000_000_02),
+            Element::Mg => Ok(0.001_5),
+            Element::Al => Ok(0.000_000_7),
+            Element::Si => Ok(0.001),
+            Element::P => Ok(0.000_000_005),
+            Element::S => Ok(0.008_5),
+            Element::Cl => Ok(0.005_5),
+            Element::Ar => Ok(0.001),
+            Element::K => Ok(0.000_1),
+            Element::Ca => Ok(0.004),
+            Element::Sc => Ok(0.000_005),
+            Element::Ti => Ok(0.001),
+            Element::V => Ok(0.000_1),
+            Element::Cr => Ok(0.000_6),
+            Element::Mn => Ok(0.000_003),
+            Element::Fe => Ok(0.002),
+            Element::Co => Ok(0.000_004),
+            Element::Ni => Ok(0.000_4),
+            Element::Cu => Ok(0.003),
+            Element::Zn => Ok(0.02),
+            Element::Ga => Ok(0.001),
+            Element::Ge => Ok(0.008),
+            Element::As => Ok(0.000_006),
+            Element::Se => Ok(0.008),
+            Element::Br => Ok(0.003),
+            Element::Kr => Ok(0.002),
+            Element::Rb => Ok(0.000_3),
+            Element::Sr => Ok(0.01),
+            Element::Y => Ok(0.000_02),
+            Element::Zr => Ok(0.002),
+            Element::Nb => Ok(0.000_02),
+            Element::Mo => Ok(0.01),
+            Element::Ru => Ok(0.02),
+            Element::Rh => Ok(0.000_02),
+            Element::Pd => Ok(0.01),
+            Element::Ag => Ok(0.000_2),
+            Element::Cd => Ok(0.004),
+            Element::In => Ok(0.001),
+            Element::Sn => Ok(0.007),
+            Element::Sb => Ok(0.001),
+            Element::Te => Ok(0.03),
+            Element::I => Ok(0.000_03),
+            Element::Xe => Ok(0.006),
+            Element::Cs => Ok(0.000_000_06),
+            Element::Ba => Ok(0.007),
+            Element::La => Ok(0.000_07),
+            Element::Ce => Ok(0.001),
+            Element::Pr => Ok(0.000_02),
+            Element::Nd => Ok(0.003),
+            Element::Sm => Ok(0.02),
+            Element::Eu => Ok(0.001),
+            Element::Gd => Ok(0.03),
+            Element::Tb => Ok(0.000_02),
+            Element::Dy => Ok(0.001),
+            Element::Ho => Ok(0.000_02),
+            Element::Er => Ok(0.003),
+            Element::Tm => Ok(0.000_02),
+            Element::Yb => Ok(0.005),
+            Element::Lu => Ok(0.000_1),
+            Element::Hf => Ok(0.02),
+            Element::Ta => Ok(0.000_02),
+            Element::W => Ok(0.01),
+            Element::Re => Ok(0.001),
+            Element::Os => Ok(0.03),
+            Element::Ir => Ok(0.003),
+            Element::Pt => Ok(0.009),
+            Element::Au => Ok(0.000_005),
+            Element::Hg => Ok(0.003),
+            Element::Tl => Ok(0.001_5),
+            Element::Pb => Ok(0.1),
+            Element::Bi => Ok(0.000_01),
+            Element::Th => Ok(0.000_4),
+            Element::Pa => Ok(0.000_02),
+            Element::U => Ok(0.000_03),
+            _ => Err(Error::UnsupportedElement(*self)),
+        }
+    }
+
+    /// Returns the Pauling electronegativity of the Element, or None if no
+    /// value is available, e.g. for He, Ne and Ar.
+    pub fn electronegativity(&self) -> Option<f64> {
+        match self {
+            Element::H => Some(2.20),
+            Element::Li => Some(0.98),
+            Element::Be => Some(1.57),
+            Element::B => Some(2.04),
+            Element::C => Some(2.55),
+            Element::N => Some(3.04),
+            Element::O => Some(3.44),
+            Element::F => Some(3.98),
+            Element::Na => Some(0.93),
+            Element::Mg => Some(1.31),
+            Element::Al => Some(1.61),
+            Element::Si => Some(1.90),
+            Element::P => Some(2.19),
+            Element::S => Some(2.58),
+            Element::Cl => Some(3.16),
+            Element::K => Some(0.82),
+            Element::Ca => Some(1.00),
+            Element::Sc => Some(1.36),
+            Element::Ti => Some(1.54),
+            Element::V => Some(1.63),
+            Element::Cr => Some(1.66),
+            Element::Mn => Some(1.55),
+            Element::Fe => Some(1.83),
+            Element::Co => Some(1.88),
+            Element::Ni => Some(1.91),
+            Element::Cu => Some(1.90),
+            Element::Zn => Some(1.65),
+            Element::Ga => Some(1.81),
+            Element::Ge => Some(2.01),
+            Element::As => Some(2.18),
+            Element::Se => Some(2.55),
+            Element::Br => Some(2.96),
+            Element::Kr => Some(3.00),
+            Element::Rb => Some(0.82),
+            Element::Sr => Some(0.95),
+            Element::Y => Some(1.22),
+            Element::Zr => Some(1.33),
+            Element::Nb => Some(1.6),
+            Element::Mo => Some(2.16),
+            Element::Tc => Some(1.9),
+            Element::Ru => Some(2.2),
+            Element::Rh => Some(2.28),
+            Element::Pd => Some(2.20),
+            Element::Ag => Some(1.93),
+            Element::Cd => Some(1.69),
+            Element::In => Some(1.78),
+            Element::Sn => Some(1.96),
+            Element::Sb => Some(2.05),
+            Element::Te => Some(2.1),
+            Element::I => Some(2.66),
+            Element::Xe => Some(2.60),
+            Element::Cs => Some(0.79),
+            Element::Ba => Some(0.89),
+            Element::La => Some(1.10),
+            Element::Ce => Some(1.12),
+            Element::Pr => Some(1.13),
+            Element::Nd => Some(1.14),
+            Element::Pm => Some(1.13),
+            Element::Sm => Some(1.17),
+            Element::Eu => Some(1.2),
+            Element::Gd => Some(1.20),
+            Element::Tb => Some(1.1),
+            Element::Dy => Some(1.22),
+            Element::Ho => Some(1.23),
+            Element::Er => Some(1.24),
+            Element::Tm => Some(1.25),
+            Element::Yb => Some(1.1),
+            Element::Lu => Some(1.27),
+            Element::Hf => Some(1.3),
+            Element::Ta => Some(1.5),
+            Element::W => Some(2.36),
+            Element::Re => Some(1.9),
+            Element::Os => Some(2.2),
+            Element::Ir => Some(2.20),
+            Element::Pt => Some(2.28),
+            Element::Au => Some(2.54),
+            Element::Hg => Some(2.00),
+            Element::Tl => Some(1.62),
+            Element::Pb => Some(2.33),
+            Element::Bi => Some(2.02),
+            Element::Po => Some(2.0),
+            Element::At => Some(2.2),
+            Element::Rn => Some(2.2),
+            Element::Fr => Some(0.7),
+            Element::Ra => Some(0.9),
+            Element::Ac => Some(1.1),
+            Element::Th => Some(1.3),
+            Element::Pa => Some(1.5),
+            Element::U => Some(1.38),
+            Element::Np => Some(1.36),
+            Element::Pu => Some(1.28),
+            Element::Am => Some(1.3),
+            Element::Cm => Some(1.3),
+            Element::Bk => Some(1.3),
+            Element::Cf => Some(1.3),
+            Element::Es => Some(1.3),
+            Element::Fm => Some(1.3),
+            Element::Md => Some(1.3),
+            Element::No => Some(1.3),
+            _ => None,
+        }
+    }
+
+    /// Returns the single-bond covalent radius of the Element in picometers,
+    /// or None if no value is available.
+    ///
+    /// Radii are sourced from Cordero et al. (2008), using sp3 C and the
+    /// low-spin values for Mn, Fe and Co.
+    pub fn covalent_radius(&self) -> Option<f64> {
+        match self {
+            Element::H => Some(31.0),
+            Element::He => Some(28.0),
+            Element::Li => Some(128.0),
+            Element::Be => Some(96.0),
+            Element::B => Some(84.0),
+            Element::C => Some(76.0),
+            Element::N => Some(71.0),
+            Element::O => Some(66.0),
+            Element::F => Some(57.0),
+            Element::Ne => Some(58.0),
+            Element::Na => Some(166.0),
+            Element::Mg => Some(141.0),
+            Element::Al => Some(121.0),
+            Element::Si => Some(111.0),
+            Element::P => Some(107.0),
+            Element::S => Some(105.0),
+            Element::Cl => Some(102.0),
+            Element::Ar => Some(106.0),
+            Element::K => Some(203.0),
+            Element::Ca => Some(176.0),
+            Element::Sc => Some(170.0),
+            Element::Ti => Some(160.0),
+            Element::V => Some(153.0),
+            Element::Cr => Some(139.0),
+            Element::Mn => Some(139.0),
+            Element::Fe => Some(132.0),
+            Element::Co => Some(126.0),
+            Element::Ni => Some(124.0),
+            Element::Cu => Some(132.0),
+            Element::Zn => Some(122.0),
+            Element::Ga => Some(122.0),
+            Element::Ge => Some(120.0),
+            Element::As => Some(119.0),
+            Element::Se => Some(120.0),
+            Element::Br => Some(120.0),
+            Element::Kr => Some(116.0),
+            Element::Rb => Some(220.0),
+            Element::Sr => Some(195.0),
+            Element::Y => Some(190.0),
+            Element::Zr => Some(175.0),
+            Element::Nb => Some(164.0),
+            Element::Mo => Some(154.0),
+            Element::Tc => Some(147.0),
+            Element::Ru => Some(146.0),
+            Element::Rh => Some(142.0),
+            Element::Pd => Some(139.0),
+            Element::Ag => Some(145.0),
+            Element::Cd => Some(144.0),
+            Element::In => Some(142.0),
+            Element::Sn => Some(139.0),
+            Element::Sb => Some(139.0),
+            Element::Te => Some(138.0),
+            Element::I => Some(139.0),
+            Element::Xe => Some(140.0),
+            Element::Cs => Some(244.0),
+            Element::Ba => Some(215.0),
+            Element::La => Some(207.0),
+            Element::Ce => Some(204.0),
+            Element::Pr => Some(203.0),
+            Element::Nd => Some(201.0),
+            Element::Pm => Some(199.0),
+            Element::Sm => Some(198.0),
+            Element::Eu => Some(198.0),
+            Element::Gd => Some(196.0),
+            Element::Tb => Some(194.0),
+            Element::Dy => Some(192.0),
+            Element::Ho => Some(192.0),
+            Element::Er => Some(189.0),
+            Element::Tm => Some(190.0),
+            Element::Yb => Some(187.0),
+            Element::Lu => Some(187.0),
+            Element::Hf => Some(175.0),
+            Element::Ta => Some(170.0),
+            Element::W => Some(162.0),
+            Element::Re => Some(151.0),
+            Element::Os => Some(144.0),
+            Element::Ir => Some(141.0),
+            Element::Pt => Some(136.0),
+            Element::Au => Some(136.0),
+            Element::Hg => Some(132.0),
+            Element::Tl => Some(145.0),
+            Element::Pb => Some(146.0),
+            Element::Bi => Some(148.0),
+            Element::Po => Some(140.0),
+            Element::At => Some(150.0),
+            Element::Rn => Some(150.0),
+            Element::Fr => Some(260.0),
+            Element::Ra => Some(221.0),
+            Element::Ac => Some(215.0),
+            Element::Th => Some(206.0),
+            Element::Pa => Some(200.0),
+            Element::U => Some(196.0),
+            Element::Np => Some(190.0),
+            Element::Pu => Some(187.0),
+            Element::Am => Some(180.0),
+            Element::Cm => Some(169.0),
+            _ => None,
+        }
+    }
+
+    /// Returns a fixed-length feature vector of the Element for quick machine
+    /// learning baselines. Each entry is scaled to [0, 1] and missing values
+    /// are 0.0. The layout (N = 6) is:
+    ///
+    /// 0. atomic number / 118
+    /// 1. `electronegativity` / 3.98 (F)
+    /// 2. `covalent_radius` / 260 pm (Fr)
+    /// 3. `group` / 18
+    /// 4. `period` / 7
+    /// 5. number of electrons in the outermost s and p subshells / 8
+    ///
+    /// All entries are 0.0 for Any.
+    pub fn feature_vector(&self) -> [f64; 6] {
+        let n_outer_electrons = match (self.subshell_occupancies(), self.period()) {
+            (Some(occupancies), Some(period)) => SUBSHELLS
+                .iter()
+                .zip(occupancies)
+                .filter(|&(&(n, l), _)| n == period && l <= 1)
+                .map(|(_, occupancy)| occupancy)
+                .sum(),
+            _ => 0,
+        };
+
+        [
+            f64::from(self.atomic_number()) / 118.0,
+            self.electronegativity().unwrap_or(0.0) / 3.98,
+            self.covalent_radius().unwrap_or(0.0) / 260.0,
+            f64::from(self.group().unwrap_or(0)) / 18.0,
+            f64::from(self.period().unwrap_or(0)) / 7.0,
+            f64::from(n_outer_electrons) / 8.0,
+        ]
+    }
+
+    /// Returns the (lower, upper) bounds of the standard atomic weight of the
+    /// Element for the 14 elements that CIAAW gives as an interval, or None
+    /// for elements with a single value.
+    ///
+    /// Intervals for Ar (2017) and Pb (2020) postdate `WEIGHTS_SOURCE_YEAR`,
+    /// so `Element::atomic_weight` still gives their 2013 single value for
+    /// these two, which lies within but not at the middle of the interval.
+    pub fn atomic_weight_interval(&self) -> Option<(f64, f64)> {
+        match self {
+            Element::H => Some((1.007_84, 1.008_11)),
+            Element::Li => Some((6.938, 6.997)),
+            Element::B => Some((10.806, 10.821)),
+            Element::C => Some((12.009_6, 12.011_6)),
+            Element::N => Some((14.006_43, 14.007_28)),
+            Element::O => Some((15.999_03, 15.999_77)),
+            Element::Mg => Some((24.304, 24.307)),
+            Element::Si => Some((28.084, 28.086)),
+            Element::S => Some((32.059, 32.076)),
+            Element::Cl => Some((35.446, 35.457)),
+            Element::Ar => Some((39.792, 39.963)),
+            Element::Br => Some((79.901, 79.907)),
+            Element::Tl => Some((204.382, 204.385)),
+            Element::Pb => Some((206.14, 207.94)),
+            _ => None,
+        }
+    }
+
+    /// Returns the conventional family name of the Element, e.g. "Halogens"
+    /// for Cl, or None for Any and for H, which belongs to no family.
+    pub fn group_name(&self) -> Option<&'static str> {
+        if self.is_alkali_metal() {
+            Some("Alkali metals")
+        } else if self.is_alkaline_earth_metal() {
+            Some("Alkaline earth metals")
+        } else if self.is_lanthanide() {
+            Some("Lanthanides")
+        } else if self.is_actinide() {
+            Some("Actinides")
+        } else if self.is_transition_metal() {
+            Some("Transition metals")
+        } else if self.is_halogen() {
+            Some("Halogens")
+        } else if self.is_noble_gas() {
+            Some("Noble gases")
+        } else {
+            match self.group() {
+                Some(13) => Some("Boron group"),
+                Some(14) => Some("Carbon group"),
+                Some(15) => Some("Pnictogens"),
+                Some(16) => Some("Chalcogens"),
+                _ => None,
+            }
+        }
+    }
+
+    /// Returns an Element that the symbol of this Element is easily confused
+    /// with, or None if there is no such Element. Useful to flag likely
+    /// data-entry swaps.
+    ///
+    /// The Element whose symbol is the reverse of this one is preferred, e.g.
+    /// In for Ni. Otherwise, if the symbol written in uppercase reads as two
+    /// one-letter symbols, the Element of the first letter is given, e.g. C
+    /// for Co ("CO") and H for Ho ("HO").
+    pub fn swappable_symbol(&self) -> Option<Element> {
+        let mut chars = self.symbol().chars();
+        let (first, second) = match (chars.next(), chars.next()) {
+            (Some(first), Some(second)) => (first, second),
+            _ => return None,
+        };
+
+        if let Ok(reversed) = format!("{second}{first}").parse() {
+            return Some(reversed);
+        }
+        let head = first.to_string().parse().ok()?;
+        second.to_string().parse::<Element>().ok()?;
+        Some(head)
+    }
+
+    /// Returns true for every Element except the pseudo-element Any.
+    pub fn is_real(&self) -> bool {
+        *self != Element::Any
+    }
+
+    /// Returns the nominal mass of the Element, i.e. the integer mass number
+    /// of its most abundant isotope, or None if no abundance data is
+    /// available. See `Element::monoisotopic_mass` for the exact mass.
+    pub fn nominal_mass(&self) -> Option<u16> {
+        self.most_abundant_isotope()
+    }
+
+    /// Returns the empirical atomic radius of the Element in picometers, or
+    /// None if no value is available, e.g. for the noble gases.
+    ///
+    /// Radii are sourced from Slater (1964), who derived them from bond
+    /// lengths in crystals and molecules to an accuracy of about 5 pm. They
+    /// differ from `Element::covalent_radius`, e.g. 70 pm versus 76 pm for C.
+    pub fn atomic_radius(&self) -> Option<f64> {
+        match self {
+            Element::H => Some(25.0),
+            Element::Li => Some(145.0),
+            Element::Be => Some(105.0),
+            Element::B => Some(85.0),
+            Element::C => Some(70.0),
+            Element::N => Some(65.0),
+            Element::O => Some(60.0),
+            Element::F => Some(50.0),
+            Element::Na => Some(180.0),
+            Element::Mg => Some(150.0),
+            Element::Al => Some(125.0),
+            Element::Si => Some(110.0),
+            Element::P => Some(100.0),
+            Element::S => Some(100.0),
+            Element::Cl => Some(100.0),
+            Element::K => Some(220.0),
+            Element::Ca => Some(180.0),
+            Element::Sc => Some(160.0),
+            Element::Ti => Some(140.0),
+            Element::V => Some(135.0),
+            Element::Cr => Some(140.0),
+            Element::Mn => Some(140.0),
+            Element::Fe => Some(140.0),
+            Element::Co => Some(135.0),
+            Element::Ni => Some(135.0),
+            Element::Cu => Some(135.0),
+            Element::Zn => Some(135.0),
+            Element::Ga => Some(130.0),
+            Element::Ge => Some(125.0),
+            Element::As => Some(115.0),
+            Element::Se => Some(115.0),
+            Element::Br => Some(115.0),
+            Element::Rb => Some(235.0),
+            Element::Sr => Some(200.0),
+            Element::Y => Some(180.0),
+            Element::Zr => Some(155.0),
+            Element::Nb => Some(145.0),
+            Element::Mo => Some(145.0),
+            Element::Tc => Some(135.0),
+            Element::Ru => Some(130.0),
+            Element::Rh => Some(135.0),
+            Element::Pd => Some(140.0),
+            Element::Ag => Some(160.0),
+            Element::Cd => Some(155.0),
+            Element::In => Some(155.0),
+            Element::Sn => Some(145.0),
+            Element::Sb => Some(145.0),
+            Element::Te => Some(140.0),
+            Element::I => Some(140.0),
+            Element::Cs => Some(260.0),
+            Element::Ba => Some(215.0),
+            Element::La => Some(195.0),
+            Element::Ce => Some(185.0),
+            Element::Pr => Some(185.0),
+            Element::Nd => Some(185.0),
+            Element::Pm => Some(185.0),
+            Element::Sm => Some(185.0),
+            Element::Eu => Some(185.0),
+            Element::Gd => Some(180.0),
+            Element::Tb => Some(175.0),
+            Element::Dy => Some(175.0),
+            Element::Ho => Some(175.0),
+            Element::Er => Some(175.0),
+            Element::Tm => Some(175.0),
+            Element::Yb => Some(175.0),
+            Element::Lu => Some(175.0),
+            Element::Hf => Some(155.0),
+            Element::Ta => Some(145.0),
+            Element::W => Some(135.0),
+            Element::Re => Some(135.0),
+            Element::Os => Some(130.0),
+            Element::Ir => Some(135.0),
+            Element::Pt => Some(135.0),
+            Element::Au => Some(135.0),
+            Element::Hg => Some(150.0),
+            Element::Tl => Some(190.0),
+            Element::Pb => Some(180.0),
+            Element::Bi => Some(160.0),
+            Element::Po => Some(190.0),
+            Element::Ra => Some(215.0),
+            Element::Ac => Some(195.0),
+            Element::Th => Some(180.0),
+            Element::Pa => Some(180.0),
+            Element::U => Some(175.0),
+            Element::Np => Some(175.0),
+            Element::Pu => Some(175.0),
+            Element::Am => Some(175.0),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of implicit hydrogens of an atom of the Element
+    /// with formal_charge whose bonds to explicit neighbors sum to
+    /// bond_order_sum, following the OpenSMILES implicit hydrogen rules.
+    ///
+    /// The smallest of `Element::valences` that is at least bond_order_sum is
+    /// used, and 0 is returned if the atom is already saturated or
+    /// hypervalent. Elements without valences, such as metals, give an
+    /// `Error::UnsupportedElement`.
+    pub fn implicit_hydrogens(&self, formal_charge: i8, bond_order_sum: u8) -> Result<u8, Error> {
+        Ok(self
+            .valences(formal_charge)?
+            .iter()
+            .find(|&&valence| valence >= bond_order_sum)
+            .map_or(0, |valence| valence - bond_order_sum))
+    }
+
+    /// Returns true if the Element has at least one stable isotope, i.e. it is
+    /// not Any, Tc, Pm or Bi onwards. Bi-209 is radioactive, albeit with a
+    /// half-life of about 2e19 years.
+    pub fn has_stable_isotope(&self) -> bool {
+        self.is_real() && !matches!(self, Element::Tc | Element::Pm) && self.atomic_number() < 83
+    }
+
+    /// Returns true if the Element has no stable isotope, see
+    /// `Element::has_stable_isotope`.
+    ///
+    /// This is a property of the element rather than of a sample: elements
+    /// such as C and K with trace natural radioisotopes (C-14, K-40) are not
+    /// considered radioactive.
+    pub fn is_radioactive(&self) -> bool {
+        self.is_real() && !self.has_stable_isotope()
+    }
+
+    /// Returns the Element at period and group of the periodic table, the
+    /// inverse of `Element::period` and `Element::group`, or None if the cell
+    /// is empty.
+    ///
+    /// The f-block has no group, so the lanthanides and actinides are never
+    /// returned and group 3 of periods 6 and 7 is empty.
+    pub fn from_period_group(period: u8, group: u8) -> Option<Element> {
+        (1..=118)
+            .filter_map(Element::from_atomic_number)
+            .find(|element| element.period() == Some(period) && element.group() == Some(group))
+    }
+
+    /// Returns the Element below this one in its group, skipping empty cells,
+    /// or None at the bottom of the group. See `Element::from_period_group`.
+    ///
+    /// Returns None for Any and for the f-block, which have no group.
+    pub fn next_in_group(&self) -> Option<Element> {
+        let (group, period) = self.column_major_key()?;
+        (period + 1..=7).find_map(|period| Element::from_period_group(period, group))
+    }
+
+    /// Returns the Element above this one in its group, skipping empty cells,
+    /// or None at the top of the group.
+    ///
+    /// Returns None for Any and for the f-block, which have no group.
+    pub fn prev_in_group(&self) -> Option<Element> {
+        let (group, period) = self.column_major_key()?;
+        (1..period)
+            .rev()
+            .find_map(|period| Element::from_period_group(period, group))
+    }
+
+    /// Returns the Element to the right of this one in its period, skipping
+    /// empty cells and the f-block, or None at the end of the period.
+    ///
+    /// Returns None for Any and for the f-block, which have no group.
+    pub fn next_in_period(&self) -> Option<Element> {
+        let (group, period) = self.column_major_key()?;
+        (group + 1..=18).find_map(|group| Element::from_period_group(period, group))
+    }
+
+    /// Returns the Element to the left of this one in its period, skipping
+    /// empty cells and the f-block, or None at the start of the period.
+    ///
+    /// Returns None for Any and for the f-block, which have no group.
+    pub fn prev_in_period(&self) -> Option<Element> {
+        let (group, period) = self.column_major_key()?;
+        (1..group)
+            .rev()
+            .find_map(|group| Element::from_period_group(period, group))
+    }
+
+    /// Returns the number of neutrons in the isotope of the Element with
+    /// mass_number, or `Error::InvalidIsotope` if mass_number is less than the
+    /// atomic number.
+    pub fn neutron_count(&self, mass_number: u16) -> Result<u16, Error> {
+        mass_number
+            .checked_sub(u16::from(self.atomic_number()))
+            .ok_or(Error::InvalidIsotope(*self, mass_number))
+    }
+
+    /// Returns the number of protons of the Element, i.e. its atomic number.
+    pub fn proton_count(&self) -> u8 {
+        self.atomic_number()
+    }
+
+    /// Returns the number of electrons of an atom of the Element with
+    /// formal_charge.
+    ///
+    /// Returns `Error::InvalidFormalCharge` if formal_charge exceeds the
+    /// atomic number, and `Error::UnsupportedElement` for Any.
+    pub fn electron_count(&self, formal_charge: i8) -> Result<u8, Error> {
+        if !self.is_real() {
+            return Err(Error::UnsupportedElement(*self));
+        }
+
+        u8::try_from(i16::from(self.atomic_number()) - i16::from(formal_charge))
+            .map_err(|_| Error::InvalidFormalCharge(*self, formal_charge))
+    }
+
+    /// Returns true if bond_order_sum exceeds the largest of
+    /// `Element::valences` for formal_charge, i.e. the atom is over-bonded
+    /// even allowing for the hypervalent states of N, P and S. Elements
+    /// without valences give an `Error::UnsupportedElement`.
+    pub fn is_hypervalent(&self, formal_charge: i8, bond_order_sum: u8) -> Result<bool, Error> {
+        let valences = self.valences(formal_charge)?;
+        Ok(valences.iter().all(|&valence| bond_order_sum > valence))
+    }
+
+    /// Parses s like `FromStr` after stripping surrounding ASCII whitespace,
+    /// e.g. " Fe\n", as found in columnar formats like PDB or XYZ. Internal
+    /// whitespace as in "C l" is still an error.
+    pub fn from_str_trimmed(s: &str) -> Result<Element, Error> {
+        s.trim_matches(|c: char| c.is_ascii_whitespace())
+            .parse()
+            .map_err(|_| Error::InvalidAtomicSymbol(s.to_owned()))
+    }
+
+    /// Parses an atomic symbol from an XYZ file, where "FE", "fe" and "Fe" are
+    /// all used, by normalizing it to first-upper-rest-lower casing before
+    /// matching. Surrounding ASCII whitespace is ignored.
+    ///
+    /// Each token is treated as a single element, so "CO" is cobalt rather
+    /// than carbon and oxygen.
+    pub fn from_xyz_symbol(s: &str) -> Result<Element, Error> {
+        let mut chars = s.trim_matches(|c: char| c.is_ascii_whitespace()).chars();
+        let normalized = match chars.next() {
+            Some(first) => {
+                first.to_ascii_uppercase().to_string() + &chars.as_str().to_ascii_lowercase()
+            }
+            None => String::new(),
+        };
+
+        match normalized.parse::<Element>() {
+            Ok(element) if element.symbol() == normalized => Ok(element),
+            _ => Err(Error::InvalidAtomicSymbol(s.to_owned())),
+        }
+    }
+
+    /// Returns true if the Element is a main-group element, i.e. in groups 1,
+    /// 2 or 13-18. H counts as main-group through group 1 and He through
+    /// group 18, although He is an s-block element.
+    pub fn is_main_group(&self) -> bool {
+        matches!(self.group(), Some(1 | 2 | 13..=18))
+    }
+
+    /// Returns the IUPAC English name of the Element, e.g. "Iron" for Fe, or
+    /// "Any" for Any.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Element::Any => "Any",
+            Element::H => "Hydrogen",
+            Element::He => "Helium",
+            Element::Li => "Lithium",
+            Element::Be => "Beryllium",
+            Element::B => "Boron",
+            Element::C => "Carbon",
+            Element::N => "Nitrogen",
+            Element::O => "Oxygen",
+            Element::F => "Fluorine",
+            Element::Ne => "Neon",
+            Element::Na => "Sodium",
+            Element::Mg => "Magnesium",
+            Element::Al => "Aluminium",
+            Element::Si => "Silicon",
+            Element::P => "Phosphorus",
+            Element::S => "Sulfur",
+            Element::Cl => "Chlorine",
+            Element::Ar => "Argon",
+            Element::K => "Potassium",
+            Element::Ca => "Calcium",
+            Element::Sc => "Scandium",
+            Element::Ti => "Titanium",
+            Element::V => "Vanadium",
+            Element::Cr => "Chromium",
+            Element::Mn => "Manganese",
+            Element::Fe => "Iron",
+            Element::Co => "Cobalt",
+            Element::Ni => "Nickel",
+            Element::Cu => "Copper",
+            Element::Zn => "Zinc",
+            Element::Ga => "Gallium",
+            Element::Ge => "Germanium",
+            Element::As => "Arsenic",
+            Element::Se => "Selenium",
+            Element::Br => "Bromine",
+            Element::Kr => "Krypton",
+            Element::Rb => "Rubidium",
+            Element::Sr => "Strontium",
+            Element::Y => "Yttrium",
+            Element::Zr => "Zirconium",
+            Element::Nb => "Niobium",
+            Element::Mo => "Molybdenum",
+            Element::Tc => "Technetium",
+            Element::Ru => "Ruthenium",
+            Element::Rh => "Rhodium",
+            Element::Pd => "Palladium",
+            Element::Ag => "Silver",
+            Element::Cd => "Cadmium",
+            Element::In => "Indium",
+            Element::Sn => "Tin",
+            Element::Sb => "Antimony",
+            Element::Te => "Tellurium",
+            Element::I => "Iodine",
+            Element::Xe => "Xenon",
+            Element::Cs => "Caesium",
+            Element::Ba => "Barium",
+            Element::La => "Lanthanum",
+            Element::Ce => "Cerium",
+            Element::Pr => "Praseodymium",
+            Element::Nd => "Neodymium",
+            Element::Pm => "Promethium",
+            Element::Sm => "Samarium",
+            Element::Eu => "Europium",
+            Element::Gd => "Gadolinium",
+            Element::Tb => "Terbium",
+            Element::Dy => "Dysprosium",
+            Element::Ho => "Holmium",
+            Element::Er => "Erbium",
+            Element::Tm => "Thulium",
+            Element::Yb => "Ytterbium",
+            Element::Lu => "Lutetium",
+            Element::Hf => "Hafnium",
+            Element::Ta => "Tantalum",
+            Element::W => "Tungsten",
+            Element::Re => "Rhenium",
+            Element::Os => "Osmium",
+            Element::Ir => "Iridium",
+            Element::Pt => "Platinum",
+            Element::Au => "Gold",
+            Element::Hg => "Mercury",
+            Element::Tl => "Thallium",
+            Element::Pb => "Lead",
+            Element::Bi => "Bismuth",
+            Element::Po => "Polonium",
+            Element::At => "Astatine",
+            Element::Rn => "Radon",
+            Element::Fr => "Francium",
+            Element::Ra => "Radium",
+            Element::Ac => "Actinium",
+            Element::Th => "Thorium",
+            Element::Pa => "Protactinium",
+            Element::U => "Uranium",
+            Element::Np => "Neptunium",
+            Element::Pu => "Plutonium",
+            Element::Am => "Americium",
+            Element::Cm => "Curium",
+            Element::Bk => "Berkelium",
+            Element::Cf => "Californium",
+            Element::Es => "Einsteinium",
+            Element::Fm => "Fermium",
+            Element::Md => "Mendelevium",
+            Element::No => "Nobelium",
+            Element::Lr => "Lawrencium",
+            Element::Rf => "Rutherfordium",
+            Element::Db => "Dubnium",
+            Element::Sg => "Seaborgium",
+            Element::Bh => "Bohrium",
+            Element::Hs => "Hassium",
+            Element::Mt => "Meitnerium",
+            Element::Ds => "Darmstadtium",
+            Element::Rg => "Roentgenium",
+            Element::Cn => "Copernicium",
+            Element::Nh => "Nihonium",
+            Element::Fl => "Flerovium",
+            Element::Mc => "Moscovium",
+            Element::Lv => "Livermorium",
+            Element::Ts => "Tennessine",
+            Element::Og => "Oganesson",
+        }
+    }
+
+    /// Parses s as an atomic symbol like `FromStr`, or as an atomic number if
+    /// it is purely numeric, e.g. both "C" and "6" give C.
+    ///
+    /// Numbers from 119 to 255 give `Error::InvalidAtomicNumber`, and numbers
+    /// too large for a u8 give `Error::InvalidAtomicSymbol` with s.
+    pub fn parse_any(s: &str) -> Result<Element, Error> {
+        if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return s.parse();
+        }
+
+        match s.parse::<u8>() {
+            Ok(n) => Element::try_from(n),
+            Err(_) => Err(Error::InvalidAtomicSymbol(s.to_owned())),
+        }
+    }
+
+    /// Returns the average atomic mass of the Element computed from its
+    /// isotope masses and `Element::isotope_abundances`, or None if no
+    /// abundance data is available. Useful to sanity check
+    /// `Element::atomic_weight`.
+    pub fn computed_average_mass(&self) -> Option<f64> {
+        let abundances = self.isotope_abundances();
+        if abundances.is_empty() {
+            return None;
+        }
+
+        abundances
+            .iter()
+            .map(|&(isotope, abundance)| Some(self.atomic_weight(Some(isotope)).ok()? * abundance))
+            .sum()
+    }
+
+    /// Returns the atomic weight of the Element for a custom isotope
+    /// composition given as (mass number, fraction) pairs, e.g. for labeled
+    /// compounds, generalizing `Element::atomic_weight`.
+    ///
+    /// Returns `Error::InvalidIsotope` for isotopes unknown to
+    /// `Element::atomic_weight`, and `Error::InvalidComposition` if a fraction
+    /// is negative or the fractions do not sum to 1.0 within 1e-6.
+    pub fn atomic_weight_with_composition(&self, composition: &[(u16, f64)]) -> Result<f64, Error> {
+        let mut total_fraction = 0.0;
+        let mut weight = 0.0;
+        for &(isotope, fraction) in composition {
+            if fraction < 0.0 {
+                return Err(Error::InvalidComposition(*self));
+            }
+            weight += self.atomic_weight(Some(isotope))? * fraction;
+            total_fraction += fraction;
+        }
+        if (total_fraction - 1.0).abs() > 1e-6 {
+            return Err(Error::InvalidComposition(*self));
+        }
+
+        Ok(weight)
+    }
+
+    /// Returns the Elements in IUPAC group in ascending period order, or an
+    /// empty Vec if group is not 1-18.
+    ///
+    /// H is included in group 1, consistent with `Element::group`. Groups
+    /// 3-12 hold the transition metals only, as the f-block (lanthanides and
+    /// actinides) is not part of any numbered group.
+    pub fn group_members(group: u8) -> Vec<Element> {
+        (1..=7)
+            .filter_map(|period| Element::from_period_group(period, group))
+            .collect()
+    }
+
+    /// Returns the Elements in period in ascending atomic number order,
+    /// including the lanthanides and actinides in periods 6 and 7, or an
+    /// empty Vec if period is not 1-7.
+    pub fn period_members(period: u8) -> Vec<Element> {
+        (1..=118)
+            .filter_map(Element::from_atomic_number)
+            .filter(|element| element.period() == Some(period))
+            .collect()
+    }
+
+    /// Formats the isotope of the Element with mass_number using Unicode
+    /// superscript digits, e.g. "¹³C".
+    pub fn format_isotope_unicode(&self, mass_number: u16) -> String {
+        let mut s: String = mass_number
+            .to_string()
+            .bytes()
+            .map(|digit| SUPERSCRIPT_DIGITS[usize::from(digit - b'0')])
+            .collect();
+        s.push_str(self.symbol());
+        s
+    }
+
+    /// Parses an isotope written with a Unicode superscript mass number, e.g.
+    /// "¹³C" or "²³⁵U", the inverse of `Element::format_isotope_unicode`.
+    ///
+    /// The mass number is not checked against the known isotopes of the
+    /// Element. Returns `Error::InvalidAtomicSymbol` with all of s if the mass
+    /// number is missing or does not fit in a u16, or the symbol is invalid.
+    pub fn parse_isotope_unicode(s: &str) -> Result<(Element, u16), Error> {
+        let invalid = || Error::InvalidAtomicSymbol(s.to_owned());
+
+        let mut mass_number: Option<u16> = None;
+        let mut symbol = s;
+        for (i, c) in s.char_indices() {
+            match SUPERSCRIPT_DIGITS.iter().position(|&digit| digit == c) {
+                Some(digit) => {
+                    mass_number = mass_number
+                        .unwrap_or(0)
+                        .checked_mul(10)
+                        .and_then(|n| n.checked_add(digit as u16));
+                    if mass_number.is_none() {
+                        return Err(invalid());
+                    }
+                }
+                None => {
+                    symbol = &s[i..];
+                    break;
+                }
+            }
+        }
+
+        match (mass_number, symbol.parse()) {
+            (Some(mass_number), Ok(element)) => Ok((element, mass_number)),
+            _ => Err(invalid()),
+        }
+    }
+
+    /// Returns the single Category of the Element, or None for Any.
+    ///
+    /// Families take precedence over the metal/metalloid/nonmetal split, so
+    /// At and Ts are halogens and Og is a noble gas. The remaining metals
+    /// outside the s-, d- and f-blocks are post-transition metals, and the
+    /// remaining nonmetals (H, C, N, O, P, S and Se) are nonmetals.
+    pub fn category(&self) -> Option<Category> {
+        if !self.is_real() {
+            None
+        } else if self.is_noble_gas() {
+            Some(Category::NobleGas)
+        } else if self.is_halogen() {
+            Some(Category::Halogen)
+        } else if self.is_alkali_metal() {
+            Some(Category::AlkaliMetal)
+        } else if self.is_alkaline_earth_metal() {
+            Some(Category::AlkalineEarthMetal)
+        } else if self.is_lanthanide() {
+            Some(Category::Lanthanide)
+        } else if self.is_actinide() {
+            Some(Category::Actinide)
+        } else if self.is_transition_metal() {
+            Some(Category::TransitionMetal)
+        } else if self.is_metalloid() {
+            Some(Category::Metalloid)
+        } else if self.is_metal() {
+            Some(Category::PostTransitionMetal)
+        } else {
+            Some(Category::Nonmetal)
+        }
+    }
+
+    /// Returns the number of unpaired electrons of the neutral Element in its
+    /// ground state, or None for Any.
+    ///
+    /// Each subshell of the configuration given by `electron_configuration`,
+    /// including its d- and f-block anomalies, is filled following Hund's
+    /// rule, i.e. singly occupying every orbital before pairing.
+    pub fn unpaired_electrons(&self) -> Option<u8> {
+        let occupancies = self.subshell_occupancies()?;
+        Some(
+            SUBSHELLS
+                .iter()
+                .zip(occupancies)
+                .map(|(&(_, l), occupancy)| {
+                    let n_orbitals = 2 * l + 1;
+                    occupancy.min(2 * n_orbitals - occupancy)
+                })
+                .sum(),
+        )
+    }
+
+    /// Returns the atomic weight of the Element like `Element::atomic_weight`,
+    /// but falls back to the mass number of an isotope missing from the
+    /// table instead of erroring. Only suitable for rough calculations.
+    pub fn atomic_weight_or_nominal(&self, isotope: Option<u16>) -> f64 {
+        match self.atomic_weight(isotope) {
+            Ok(weight) => weight,
+            Err(_) => isotope.map_or(0.0, f64::from),
+        }
+    }
+
+    /// Parses an atomic symbol from ASCII bytes case-insensitively, including
+    /// "*" for Any, without UTF-8 validation or allocation on success. Useful
+    /// for tokenizers working on byte slices. `FromStr` delegates to this.
+    pub fn from_ascii(bytes: &[u8]) -> Result<Element, Error> {
+        let mut lowercase = [0; 2];
+        if bytes.len() > lowercase.len() {
+            return Err(Error::InvalidAtomicSymbol(
+                String::from_utf8_lossy(bytes).into_owned(),
+            ));
+        }
+        for (lower, byte) in lowercase.iter_mut().zip(bytes) {
+            *lower = byte.to_ascii_lowercase();
+        }
+
+        match &lowercase[..bytes.len()] {
+            b"*" => Ok(Element::Any),
+            b"h" => Ok(Element::H),
+            b"he" => Ok(Element::He),
+            b"li" => Ok(Element::Li),
+            b"be" => Ok(Element::Be),
+            b"b" => Ok(Element::B),
+            b"c" => Ok(Element::C),
+            b"n" => Ok(Element::N),
+            b"o" => Ok(Element::O),
+            b"f" => Ok(Element::F),
+            b"ne" => Ok(Element::Ne),
+            b"na" => Ok(Element::Na),
+            b"mg" => Ok(Element::Mg),
+            b"al" => Ok(Element::Al),
+            b"si" => Ok(Element::Si),
+            b"p" => Ok(Element::P),
+            b"s" => Ok(Element::S),
+            b"cl" => Ok(Element::Cl),
+            b"ar" => Ok(Element::Ar),
+            b"k" => Ok(Element::K),
+            b"ca" => Ok(Element::Ca),
+            b"sc" => Ok(Element::Sc),
+            b"ti" => Ok(Element::Ti),
+            b"v" => Ok(Element::V),
+            b"cr" => Ok(Element::Cr),
+            b"mn" => Ok(Element::Mn),
+            b"fe" => Ok(Element::Fe),
+            b"co" => Ok(Element::Co),
+            b"ni" => Ok(Element::Ni),
+            b"cu" => Ok(Element::Cu),
+            b"zn" => Ok(Element::Zn),
+            b"ga" => Ok(Element::Ga),
+            b"ge" => Ok(Element::Ge),
+            b"as" => Ok(Element::As),
+            b"se" => Ok(Element::Se),
+            b"br" => Ok(Element::Br),
+            b"kr" => Ok(Element::Kr),
+            b"rb" => Ok(Element::Rb),
+            b"sr" => Ok(Element::Sr),
+            b"y" => Ok(Element::Y),
+            b"zr" => Ok(Element::Zr),
+            b"nb" => Ok(Element::Nb),
+            b"mo" => Ok(Element::Mo),
+            b"tc" => Ok(Element::Tc),
+            b"ru" => Ok(Element::Ru),
+            b"rh" => Ok(Element::Rh),
+            b"pd" => Ok(Element::Pd),
+            b"ag" => Ok(Element::Ag),
+            b"cd" => Ok(Element::Cd),
+            b"in" => Ok(Element::In),
+            b"sn" => Ok(Element::Sn),
+            b"sb" => Ok(Element::Sb),
+            b"te" => Ok(Element::Te),
+            b"i" => Ok(Element::I),
+            b"xe" => Ok(Element::Xe),
+            b"cs" => Ok(Element::Cs),
+            b"ba" => Ok(Element::Ba),
+            b"la" => Ok(Element::La),
+            b"ce" => Ok(Element::Ce),
+            b"pr" => Ok(Element::Pr),
+            b"nd" => Ok(Element::Nd),
+            b"pm" => Ok(Element::Pm),
+            b"sm" => Ok(Element::Sm),
+            b"eu" => Ok(Element::Eu),
+            b"gd" => Ok(Element::Gd),
+            b"tb" => Ok(Element::Tb),
+            b"dy" => Ok(Element::Dy),
+            b"ho" => Ok(Element::Ho),
+            b"er" => Ok(Element::Er),
+            b"tm" => Ok(Element::Tm),
+            b"yb" => Ok(Element::Yb),
+            b"lu" => Ok(Element::Lu),
+            b"hf" => Ok(Element::Hf),
+            b"ta" => Ok(Element::Ta),
+            b"w" => Ok(Element::W),
+            b"re" => Ok(Element::Re),
+            b"os" => Ok(Element::Os),
+            b"ir" => Ok(Element::Ir),
+            b"pt" => Ok(Element::Pt),
+            b"au" => Ok(Element::Au),
+            b"hg" => Ok(Element::Hg),
+            b"tl" => Ok(Element::Tl),
+            b"pb" => Ok(Element::Pb),
+            b"bi" => Ok(Element::Bi),
+            b"po" => Ok(Element::Po),
+            b"at" => Ok(Element::At),
+            b"rn" => Ok(Element::Rn),
+            b"fr" => Ok(Element::Fr),
+            b"ra" => Ok(Element::Ra),
+            b"ac" => Ok(Element::Ac),
+            b"th" => Ok(Element::Th),
+            b"pa" => Ok(Element::Pa),
+            b"u" => Ok(Element::U),
+            b"np" => Ok(Element::Np),
+            b"pu" => Ok(Element::Pu),
+            b"am" => Ok(Element::Am),
+            b"cm" => Ok(Element::Cm),
+            b"bk" => Ok(Element::Bk),
+            b"cf" => Ok(Element::Cf),
+            b"es" => Ok(Element::Es),
+            b"fm" => Ok(Element::Fm),
+            b"md" => Ok(Element::Md),
+            b"no" => Ok(Element::No),
+            b"lr" => Ok(Element::Lr),
+            b"rf" => Ok(Element::Rf),
+            b"db" => Ok(Element::Db),
+            b"sg" => Ok(Element::Sg),
+            b"bh" => Ok(Element::Bh),
+            b"hs" => Ok(Element::Hs),
+            b"mt" => Ok(Element::Mt),
+            b"ds" => Ok(Element::Ds),
+            b"rg" => Ok(Element::Rg),
+            b"cn" => Ok(Element::Cn),
+            b"nh" => Ok(Element::Nh),
+            b"fl" => Ok(Element::Fl),
+            b"mc" => Ok(Element::Mc),
+            b"lv" => Ok(Element::Lv),
+            b"ts" => Ok(Element::Ts),
+            b"og" => Ok(Element::Og),
+            _ => Err(Error::InvalidAtomicSymbol(
+                String::from_utf8_lossy(bytes).into_owned(),
+            )),
+        }
+    }
+
+    /// Returns the CPK color of the Element from `Element::cpk_color` as an
+    /// uppercase "#RRGGBB" hex string for SVG or HTML output.
+    pub fn cpk_color_hex(&self) -> String {
+        let [red, green, blue] = self.cpk_color();
+        format!("#{red:02X}{green:02X}{blue:02X}")
+    }
+
+    /// Parses an atomic symbol like `FromStr`, but also accepts the temporary
+    /// IUPAC systematic symbols found in historical datasets, which are mapped
+    /// to the current elements:
+    ///
+    /// - Uun: Ds
+    /// - Uuu: Rg
+    /// - Uub: Cn
+    /// - Uut: Nh
+    /// - Uuq: Fl
+    /// - Uup: Mc
+    /// - Uuh: Lv
+    /// - Uus: Ts
+    /// - Uuo: Og
+    ///
+    /// `FromStr` itself only accepts current symbols.
+    pub fn from_legacy_symbol(s: &str) -> Result<Element, Error> {
+        match s.to_lowercase().as_str() {
+            "uun" => Ok(Element::Ds),
+            "uuu" => Ok(Element::Rg),
+            "uub" => Ok(Element::Cn),
+            "uut" => Ok(Element::Nh),
+            "uuq" => Ok(Element::Fl),
+            "uup" => Ok(Element::Mc),
+            "uuh" => Ok(Element::Lv),
+            "uus" => Ok(Element::Ts),
+            "uuo" => Ok(Element::Og),
+            _ => s.parse(),
+        }
+    }
+}
+
+/// Returns the formula of the neutral binary salt formed from the common ions
+/// of cation and anion, e.g. "NaCl", "CaCl2" or "Al2O3".
+///
+/// Returns None if either Element has no common ion, or if the cation's ion is
+/// not positive or the anion's ion is not negative.
+pub fn simple_salt_formula(cation: Element, anion: Element) -> Option<String> {
+    let cation_charge = cation.common_ion_charge()?;
+    let anion_charge = anion.common_ion_charge()?;
+    if cation_charge <= 0 || anion_charge >= 0 {
+        return None;
+    }
+
+    Some(binary_formula(
+        cation,
+        cation_charge.unsigned_abs(),
+        anion,
+        anion_charge.unsigned_abs(),
+    ))
+}
+
+/// Returns the charge balanced formula of a binary compound of a cation and
+/// an anion with the given (unsigned) charges, e.g. "Al2O3".
+fn binary_formula(cation: Element, cation_charge: u8, anion: Element, anion_charge: u8) -> String {
+    let mut a = cation_charge;
+    let mut b = anion_charge;
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    let n_cation = anion_charge / a;
+    let n_anion = cation_charge / a;
+
+    let mut formula = cation.symbol().to_owned();
+    if n_cation > 1 {
+        formula.push_str(&n_cation.to_string());
+    }
+    formula.push_str(anion.symbol());
+    if n_anion > 1 {
+        formula.push_str(&n_anion.to_string());
+    }
+    formula
+}
+
+/// Returns the neutral valence of the Element, like `valence(0)`, but usable
+/// in const contexts. Returns None for elements not supported by `valence`.
+pub const fn const_valence(element: Element) -> Option<u8> {
+    match element {
+        Element::H => Some(1),
+        Element::He => Some(0),
+        Element::B => Some(3),
+        Element::C => Some(4),
+        Element::N => Some(3),
+        Element::O => Some(2),
+        Element::F => Some(1),
+        Element::Ne => Some(0),
+        Element::Si => Some(4),
+        Element::P => Some(3),
+        Element::S => Some(2),
+        Element::Se => Some(2),
+        Element::Cl => Some(1),
+        Element::Br => Some(1),
+        Element::I => Some(1),
+        Element::Ar | Element::Kr | Element::Xe | Element::Rn | Element::Og => Some(0),
+        _ => None,
+    }
+}
+
+/// The physical state of an element at standard conditions, see
+/// `Element::standard_state`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StandardState {
+    Solid,
+    Liquid,
+    Gas,
+}
+
+/// Parses a molecular formula such as "C2H6O" or "Ca(OH)2" into (Element,
+/// count) pairs in order of first appearance, merging repeated elements.
+/// Parenthesized groups may be nested and are expanded by their multiplier.
+///
+/// Unlike `FromStr` for Element, which is case-insensitive, formula parsing
+/// requires canonical symbol casing: "CO" is carbon and oxygen, "Co" is cobalt
+/// and "co" is an error. Isotope labels such as "[13C]" are an error as well,
+/// see `labeled_molecular_weight`.
+pub fn parse_formula(formula: &str) -> Result<Vec<(Element, usize)>, Error> {
+    parse_labeled_formula(formula)?
+        .into_iter()
+        .map(|((element, isotope), count)| match isotope {
+            None => Ok((element, count)),
+            Some(_) => Err(Error::InvalidFormula(formula.to_owned())),
+        })
+        .collect()
+}
+
+/// ((Element, isotope label), count) pairs of a parsed formula.
+type LabeledCounts = Vec<((Element, Option<u16>), usize)>;
+
+/// Like `parse_formula`, but also accepts isotope labels such as "[13C]",
+/// keeping labeled atoms separate from unlabeled atoms of the same Element.
+fn parse_labeled_formula(formula: &str) -> Result<LabeledCounts, Error> {
+    let mut chars = formula.chars().peekable();
+    let counts = parse_formula_group(&mut chars, formula)?;
+    match chars.next() {
+        None if !counts.is_empty() => Ok(counts),
+        _ => Err(Error::InvalidFormula(formula.to_owned())),
+    }
+}
+
+/// Parses the atoms, isotope labels and parenthesized groups of formula up to
+/// the first unmatched ')' or the end of formula.
+fn parse_formula_group(chars: &mut Peekable<Chars>, formula: &str) -> Result<LabeledCounts, Error> {
+    let invalid_formula = || Error::InvalidFormula(formula.to_owned());
+    let parse_symbol = |chars: &mut Peekable<Chars>| {
+        let mut atomic_symbol = chars
+            .next_if(char::is_ascii_uppercase)
+            .ok_or_else(invalid_formula)?
+            .to_string();
+        while let Some(c) = chars.next_if(char::is_ascii_lowercase) {
+            atomic_symbol.push(c);
+        }
+        atomic_symbol.parse::<Element>()
+    };
+
+    let mut counts: LabeledCounts = vec![];
+    while let Some(&c) = chars.peek() {
+        let group = match c {
+            '(' => {
+                chars.next();
+                let group = parse_formula_group(chars, formula)?;
+                if group.is_empty() || chars.next() != Some(')') {
+                    return Err(invalid_formula());
+                }
+                group
+            }
+            '[' => {
+                chars.next();
+                let mut digits = String::new();
+                while let Some(c) = chars.next_if(char::is_ascii_digit) {
+                    digits.push(c);
+                }
+                let isotope = digits.parse().map_err(|_| invalid_formula())?;
+                let element = parse_symbol(chars)?;
+                if chars.next() != Some(']') {
+                    return Err(invalid_formula());
+                }
+                vec![((element, Some(isotope)), 1)]
+            }
+            ')' => break,
+            _ if c.is_ascii_uppercase() => vec![((parse_symbol(chars)?, None), 1)],
+            _ => return Err(invalid_formula()),
+        };
+
+        let mut digits = String::new();
+        while let Some(c) = chars.next_if(char::is_ascii_digit) {
+            digits.push(c);
+        }
+        let multiplier: usize = match digits.as_str() {
+            "" => 1,
+            _ => digits.parse().map_err(|_| invalid_formula())?,
+        };
+
+        for (atom, count) in group {
+            let count = count.checked_mul(multiplier).ok_or_else(invalid_formula)?;
+            match counts.iter_mut().find(|(a, _)| *a == atom) {
+                Some((_, total)) => {
+                    *total = total.checked_add(count).ok_or_else(invalid_formula)?
+                }
+                None => counts.push((atom, count)),
+            }
+        }
+    }
+
+    Ok(counts)
+}
+
+/// How readily an element forms chains or rings with itself, see
+/// `Element::catenation_tendency`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CatenationLevel {
+    High,
+    Moderate,
+    Low,
+    None,
+}
+
+/// Unicode superscript digits 0-9.
+const SUPERSCRIPT_DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+/// (n, l) subshells in Madelung filling order.
+const SUBSHELLS: [(u8, u8); 19] = [
+    (1, 0),
+    (2, 0),
+    (2, 1),
+    (3, 0),
+    (3, 1),
+    (4, 0),
+    (3, 2),
+    (4, 1),
+    (5, 0),
+    (4, 2),
+    (5, 1),
+    (6, 0),
+    (4, 3),
+    (5, 2),
+    (6, 1),
+    (7, 0),
+    (5, 3),
+    (6, 2),
+    (7, 1),
+];
+
+/// Formats subshell occupancies (indexed like SUBSHELLS) ordered by n then l,
+/// skipping empty subshells.
+fn format_subshells(occupancies: &[u8; 19]) -> String {
+    let mut subshells: Vec<_> = SUBSHELLS.iter().zip(occupancies).collect();
+    subshells.sort_by_key(|(subshell, _)| **subshell);
+    subshells
+        .into_iter()
+        .filter(|(_, &occupancy)| occupancy > 0)
+        .map(|((n, l), occupancy)| format!("{n}{}{occupancy}", ['s', 'p', 'd', 'f'][*l as usize]))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns the molecular weight of formula, i.e. the sum of the standard
+/// atomic weights of its atoms.
+///
+/// Formulas are parsed with `parse_formula`, so parenthesized groups such as
+/// "Ca(OH)2" are supported.
+pub fn molecular_weight(formula: &str) -> Result<f64, Error> {
+    parse_formula(formula)?
+        .into_iter()
+        .try_fold(0.0, |weight, (element, count)| {
+            Ok(weight + element.atomic_weight(None)? * count as f64)
+        })
+}
+
+/// Returns the atom economy of a reaction as a percentage, i.e. the weight of
+/// product divided by the total weight of reactants.
+///
+/// Formulas are parsed with `parse_formula`. An empty reactants slice gives
+/// `Error::InvalidFormula` with an empty formula.
+pub fn atom_economy(reactants: &[&str], product: &str) -> Result<f64, Error> {
+    if reactants.is_empty() {
+        return Err(Error::InvalidFormula(String::new()));
+    }
+    let reactants_weight = reactants.iter().try_fold(0.0, |weight, reactant| {
+        Ok(weight + molecular_weight(reactant)?)
+    })?;
+    Ok(100.0 * molecular_weight(product)? / reactants_weight)
+}
+
+/// Parses formulas a and b with `parse_formula` and returns the combined count
+/// of each Element, e.g. "CH3" and "OH" give {C: 1, H: 4, O: 1}.
+#[cfg(feature = "std")]
+pub fn add_formulas(a: &str, b: &str) -> Result<HashMap<Element, usize>, Error> {
+    let mut counts: HashMap<Element, usize> = parse_formula(a)?.into_iter().collect();
+    for (element, count) in parse_formula(b)? {
+        let total = counts.entry(element).or_default();
+        *total = total
+            .checked_add(count)
+            .ok_or_else(|| Error::InvalidFormula(format!("{a}{b}")))?;
+    }
+    Ok(counts)
+}
+
+/// Parses formulas whole and part with `parse_formula` and returns the count of
+/// each Element left after removing part from whole, e.g. "C2H6O" minus "OH"
+/// gives {C: 2, H: 5}. Elements with no atoms left are omitted.
+///
+/// Returns `Error::InvalidFormula` for part if it is not contained in whole.
+#[cfg(feature = "std")]
+pub fn subtract_formula(whole: &str, part: &str) -> Result<HashMap<Element, usize>, Error> {
+    let mut counts: HashMap<Element, usize> = parse_formula(whole)?.into_iter().collect();
+    for (element, count) in parse_formula(part)? {
+        let total = counts
+            .get_mut(&element)
+            .and_then(|total| total.checked_sub(count))
+            .ok_or_else(|| Error::InvalidFormula(part.to_owned()))?;
+        match total {
+            0 => counts.remove(&element),
+            _ => counts.insert(element, total),
+        };
+    }
+    Ok(counts)
+}
+
+/// The year of the CIAAW standard atomic weights table used by
+/// `Element::atomic_weight` (Standard Atomic Weights 2013).
+pub const WEIGHTS_SOURCE_YEAR: u16 = 2013;
+
+/// Returns the year of the CIAAW standard atomic weights table used by
+/// `Element::atomic_weight`, so downstream users can pin the data vintage.
+pub fn weights_revision() -> u16 {
+    WEIGHTS_SOURCE_YEAR
+}
+
+/// Coarse classification of an element for four-color printed tables, see
+/// `Element::simple_color_class`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SimpleColorClass {
+    Metal,
+    Metalloid,
+    Nonmetal,
+    Unknown,
+}
+
+/// Returns the fraction of the atoms in formula that are element, e.g. 2/3 for
+/// H in "H2O", or 0.0 if element is absent. Note that this is a fraction
+/// between 0 and 1 rather than a percentage.
+///
+/// Formulas are parsed with `parse_formula`.
+pub fn atom_percent(formula: &str, element: Element) -> Result<f64, Error> {
+    let counts = parse_formula(formula)?;
+    let total: usize = counts.iter().map(|(_, count)| count).sum();
+    let target: usize = counts
+        .iter()
+        .filter(|(e, _)| *e == element)
+        .map(|(_, count)| count)
+        .sum();
+    Ok(target as f64 / total as f64)
+}
+
+/// An atom as found in a molecule: an Element with an optional isotope and a
+/// formal charge.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Atom {
+    pub element: Element,
+    pub isotope: Option<u16>,
+    pub formal_charge: i8,
+}
+
+impl Atom {
+    /// Checks that the isotope of the Atom, if any, is known to
+    /// `Element::atomic_weight` and that its formal charge is sane, i.e.
+    /// within the -15 to +15 range allowed by OpenSMILES and not leaving a
+    /// negative number of electrons.
+    ///
+    /// Returns `Error::InvalidIsotope` or `Error::InvalidFormalCharge`
+    /// respectively otherwise.
+    pub fn validate(&self) -> Result<(), Error> {
+        if let Some(isotope) = self.isotope {
+            self.element.atomic_weight(Some(isotope))?;
+        }
+
+        if self.element.is_real() {
+            self.element.electron_count(self.formal_charge)?;
+        }
+        if !(-15..=15).contains(&self.formal_charge) {
+            return Err(Error::InvalidFormalCharge(self.element, self.formal_charge));
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses a whitespace-separated list of atomic symbols, collecting every
+/// failure instead of stopping at the first one.
+///
+/// Returns the successfully parsed Elements along with the (index, token)
+/// pairs of the tokens that could not be parsed, where index is the position
+/// of the token in input.
+pub fn parse_symbols(input: &str) -> (Vec<Element>, Vec<(usize, String)>) {
+    let mut elements = Vec::new();
+    let mut failures = Vec::new();
+    for (i, token) in input.split_whitespace().enumerate() {
+        match token.parse::<Element>() {
+            Ok(element) => elements.push(element),
+            Err(_) => failures.push((i, token.to_owned())),
+        }
+    }
+
+    (elements, failures)
+}
+
+/// The behavior of an element in redox reactions, see
+/// `Element::redox_tendency`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RedoxTendency {
+    StrongOxidizer,
+    Oxidizer,
+    Reducer,
+    StrongReducer,
+}
+
+/// A specific isotope of an Element, identified by its mass number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Isotope {
+    pub element: Element,
+    pub mass_number: u16,
+}
+
+impl Isotope {
+    /// Deuterium, hydrogen-2.
+    pub const DEUTERIUM: Isotope = Isotope {
+        element: Element::H,
+        mass_number: 2,
+    };
+    /// Tritium, hydrogen-3.
+    pub const TRITIUM: Isotope = Isotope {
+        element: Element::H,
+        mass_number: 3,
+    };
+    /// Carbon-13.
+    pub const CARBON_13: Isotope = Isotope {
+        element: Element::C,
+        mass_number: 13,
+    };
+
+    /// Returns the mass of the Isotope in Daltons, see
+    /// `Element::atomic_weight`.
+    pub fn mass(&self) -> Result<f64, Error> {
+        self.element.atomic_weight(Some(self.mass_number))
+    }
+
+    /// Returns the number of neutrons of the Isotope, see
+    /// `Element::neutron_count`.
+    pub fn neutron_count(&self) -> Result<u16, Error> {
+        self.element.neutron_count(self.mass_number)
+    }
+}
+
+/// Returns the heavier of a and b by atomic number, or a if they are the same.
+pub fn heavier_of(a: Element, b: Element) -> Element {
+    if b.atomic_number() > a.atomic_number() {
+        b
+    } else {
+        a
+    }
+}
+
+/// Returns the heaviest of elements by atomic number, or None if elements is
+/// empty.
+pub fn heaviest(elements: &[Element]) -> Option<Element> {
+    elements.iter().copied().reduce(heavier_of)
+}
+
+/// Parses the element portion of a SMILES bracket atom, e.g. "13C" or "Fe",
+/// into the Element and its optional isotope prefix.
+///
+/// The isotope is not checked against the known isotopes of the Element, see
+/// `Atom::validate` for that. Returns `Error::InvalidAtomicSymbol` with all of
+/// s if the symbol is invalid or the isotope does not fit in a u16.
+pub fn parse_bracket_element(s: &str) -> Result<(Element, Option<u16>), Error> {
+    let symbol_start = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (isotope, symbol) = s.split_at(symbol_start);
+    let invalid = || Error::InvalidAtomicSymbol(s.to_owned());
+    let isotope = match isotope {
+        "" => None,
+        isotope => Some(isotope.parse().map_err(|_| invalid())?),
+    };
+
+    Ok((symbol.parse().map_err(|_| invalid())?, isotope))
+}
+
+/// Returns the molecular weight of formula with isotope labels such as
+/// "[13C]6H12O6", using the isotope mass for labeled atoms and the standard
+/// atomic weight for the rest.
+///
+/// Returns `Error::InvalidIsotope` for labels not known to
+/// `Element::atomic_weight`.
+pub fn labeled_molecular_weight(formula: &str) -> Result<f64, Error> {
+    parse_labeled_formula(formula)?.into_iter().try_fold(
+        0.0,
+        |weight, ((element, isotope), count)| {
+            Ok(weight + element.atomic_weight(isotope)? * count as f64)
+        },
+    )
+}
+
+/// Returns the sum of the standard atomic weights of atoms, one entry per
+/// atom, e.g. [C, H, H, H, H] for methane. Any contributes 0.0 like in
+/// `Element::atomic_weight`.
+pub fn total_atomic_weight(atoms: &[Element]) -> Result<f64, Error> {
+    atoms
+        .iter()
+        .try_fold(0.0, |weight, atom| Ok(weight + atom.atomic_weight(None)?))
+}
+
+/// Returns the periodic table as ASCII art, one line per period followed by a
+/// blank line and the detached lanthanide (*) and actinide (**) rows. Each
+/// cell is three characters wide, so the table fits in 54 columns and is 10
+/// lines long, and trailing whitespace is trimmed.
+pub fn ascii_periodic_table() -> String {
+    let mut table = String::new();
+    for period in 1..=7 {
+        let mut line = String::new();
+        for group in 1..=18 {
+            let cell = match (Element::from_period_group(period, group), period, group) {
+                (Some(element), _, _) => element.symbol(),
+                (None, 6, 3) => "*",
+                (None, 7, 3) => "**",
+                _ => "",
+            };
+            line.push_str(&format!("{cell:<3}"));
+        }
+        table.push_str(line.trim_end());
+        table.push('\n');
+    }
+
+    table.push('\n');
+    for (marker, f_block) in [("*", 57..=71), ("**", 89..=103)] {
+        let mut line = format!("{marker:<6}");
+        for element in f_block.filter_map(Element::from_atomic_number) {
+            line.push_str(&format!("{:<3}", element.symbol()));
+        }
+        table.push_str(line.trim_end());
+        table.push('\n');
+    }
+
+    table
+}
+
+/// Returns the isotope pattern of formula as (mass, relative intensity) peaks
+/// in ascending mass order, normalized so that the tallest peak is 1.0.
+///
+/// The abundance distributions of all atoms are convolved at unit mass
+/// resolution: isotopologues with the same nominal mass are merged into one
+/// peak at their intensity-weighted average mass. Peaks below 1e-4 relative
+/// intensity are dropped. Only elements with `Element::isotope_abundances`
+/// data are supported, others give `Error::UnsupportedElement`.
+pub fn isotope_pattern(formula: &str) -> Result<Vec<(f64, f64)>, Error> {
+    // nominal mass -> (sum of mass * probability, probability)
+    let mut peaks = BTreeMap::from([(0u32, (0.0, 1.0))]);
+    for (element, count) in parse_formula(formula)? {
+        let abundances = element.isotope_abundances();
+        if abundances.is_empty() {
+            return Err(Error::UnsupportedElement(element));
+        }
+        let isotopes = abundances
+            .iter()
+            .map(|&(isotope, abundance)| {
+                Ok((isotope, element.atomic_weight(Some(isotope))?, abundance))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        for _ in 0..count {
+            let mut convolved = BTreeMap::new();
+            for (&nominal_mass, &(weighted_mass, probability)) in &peaks {
+                for &(isotope, mass, abundance) in &isotopes {
+                    let peak = convolved
+                        .entry(nominal_mass + u32::from(isotope))
+                        .or_insert((0.0, 0.0));
+                    peak.0 += (weighted_mass + mass * probability) * abundance;
+                    peak.1 += probability * abundance;
+                }
+            }
+            peaks = convolved;
+        }
+    }
+
+    let max_probability = peaks.values().map(|&(_, p)| p).fold(0.0, f64::max);
+    Ok(peaks
+        .into_values()
+        .map(|(weighted_mass, probability)| {
+            (weighted_mass / probability, probability / max_probability)
+        })
+        .filter(|&(_, intensity)| intensity >= 1e-4)
+        .collect())
+}
+
+/// Precomputes f for every Element into an array indexed by atomic number,
+/// with Any at index 0, so that hot loops can look properties up with
+/// `table[element.atomic_number() as usize]` instead of a large match.
+pub fn property_table<T>(f: impl Fn(Element) -> T) -> [T; 119] {
+    core::array::from_fn(|n| {
+        let element = Element::from_atomic_number(n as u8).expect("n is at most 118");
+        f(element)
+    })
+}
+
+/// Serializes the Element as its atomic symbol, see `Element::symbol`.
+#[cfg(feature = "serde")]
+impl ::serde::Serialize for Element {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.symbol())
+    }
+}
+
+/// Deserializes the Element from its atomic symbol, see `FromStr`.
+#[cfg(feature = "serde")]
+impl<'de> ::serde::Deserialize<'de> for Element {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct SymbolVisitor;
+
+        impl ::serde::de::Visitor<'_> for SymbolVisitor {
+            type Value = Element;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                write!(f, "an atomic symbol")
+            }
+
+            fn visit_str<E: ::serde::de::Error>(self, v: &str) -> Result<Element, E> {
+                v.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(SymbolVisitor)
+    }
+}
+
+/// Helpers to (de)serialize an Element as its atomic number rather than its
+/// atomic symbol, e.g. `#[serde(with = "pertable::serde::as_number")]`.
+#[cfg(feature = "serde")]
+pub mod serde {
+    use super::Element;
+    use ::serde::de::Error as _;
+    use ::serde::{Deserialize, Deserializer, Serializer};
+
+    /// Serializes element as its atomic number, for use with
+    /// `#[serde(serialize_with = ...)]`.
+    pub fn serialize_as_number<S: Serializer>(
+        element: &Element,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(element.atomic_number())
+    }
+
+    /// Deserializes an Element from its atomic number, for use with
+    /// `#[serde(deserialize_with = ...)]`.
+    pub fn deserialize_from_number<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Element, D::Error> {
+        Element::try_from(u8::deserialize(deserializer)?).map_err(D::Error::custom)
+    }
+
+    /// `serialize_as_number` and `deserialize_from_number` for use with
+    /// `#[serde(with = ...)]`.
+    pub mod as_number {
+        pub use super::deserialize_from_number as deserialize;
+        pub use super::serialize_as_number as serialize;
+    }
+}
+
+/// Returns the absolute difference between the Pauling electronegativities of
+/// a and b, or None if either has no electronegativity value.
+pub fn electronegativity_difference(a: Element, b: Element) -> Option<f64> {
+    Some((a.electronegativity()? - b.electronegativity()?).abs())
+}
+
+/// Returns the fractional ionic character of a bond between a and b from
+/// Pauling's formula 1 - exp(-0.25 * diff^2), where diff is their
+/// `electronegativity_difference`, or None if either has no electronegativity
+/// value.
+#[cfg(feature = "std")]
+pub fn bond_ionicity(a: Element, b: Element) -> Option<f64> {
+    let diff = electronegativity_difference(a, b)?;
+    Some(1.0 - (-0.25 * diff * diff).exp())
+}
+
+/// The category of an element for classification and coloring, see
+/// `Element::category`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Category {
+    Nonmetal,
+    NobleGas,
+    AlkaliMetal,
+    AlkalineEarthMetal,
+    Metalloid,
+    Halogen,
+    TransitionMetal,
+    PostTransitionMetal,
+    Lanthanide,
+    Actinide,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
     fn test_error() {
         let error = Error::InvalidAtomicNumber(200);
         assert_eq!(format!("{error}"), "invalid atomic number 200".to_owned());
 
-        let error = Error::InvalidAtomicSymbol("A".to_owned());
-        assert_eq!(format!("{error}"), "invalid atomic symbol A");
+        let error = Error::InvalidAtomicSymbol("A".to_owned());
+        assert_eq!(format!("{error}"), "invalid atomic symbol A");
+
+        let error = Error::InvalidIsotope(Element::C, 10);
+        assert_eq!(format!("{error}"), "invalid isotope 10 for C");
+
+        let error = Error::InvalidFormalCharge(Element::O, -3);
+        assert_eq!(format!("{error}"), "invalid formal charge -3 for O");
+
+        let error = Error::InvalidFormula("2H".to_owned());
+        assert_eq!(format!("{error}"), "invalid formula 2H");
+
+        let error = Error::UnsupportedElement(Element::Og);
+        assert_eq!(format!("{error}"), "unsupported element Og");
+
+        let error = Error::InvalidComposition(Element::C);
+        assert_eq!(format!("{error}"), "invalid isotope composition for C");
+    }
+
+    #[test]
+    fn test_try_from_atomic_number() {
+        assert_eq!(Element::H, Element::try_from(1).unwrap());
+        assert_eq!(Element::C, Element::try_from(6).unwrap());
+        assert_eq!(Err(Error::InvalidAtomicNumber(200)), Element::try_from(200));
+    }
+
+    #[test]
+    fn test_atomic_number() {
+        assert_eq!(Element::H.atomic_number(), 1);
+        assert_eq!(Element::C.atomic_number(), 6);
+    }
+
+    #[test]
+    fn test_from_atomic_symbol() {
+        assert_eq!(Element::H, "H".parse::<Element>().unwrap());
+        assert_eq!(Element::C, "c".parse::<Element>().unwrap());
+        assert_eq!(
+            Err(Error::InvalidAtomicSymbol("A".to_owned())),
+            "A".parse::<Element>()
+        );
+        // U+212A KELVIN SIGN lowercases to "k" but is not an atomic symbol
+        assert_eq!(
+            Err(Error::InvalidAtomicSymbol("\u{212A}".to_owned())),
+            "\u{212A}".parse::<Element>()
+        );
+    }
+
+    #[test]
+    fn test_atomic_symbol() {
+        assert_eq!(Element::H.atomic_symbol(), "H".to_owned());
+        assert_eq!(Element::C.atomic_symbol(), "C".to_owned());
+    }
+
+    #[test]
+    fn test_to_str() {
+        assert_eq!(format!("{}", Element::H), "H");
+        assert_eq!(format!("{}", Element::C), "C");
+    }
+
+    #[test]
+    fn test_atomic_weight() {
+        assert_eq!(Element::H.atomic_weight(None).unwrap(), 1.007975);
+        assert_eq!(Element::C.atomic_weight(Some(13)).unwrap(), 13.003355);
+        assert_eq!(Element::Ca.atomic_weight(None).unwrap(), 40.078);
+        assert_eq!(Element::Tc.atomic_weight(None).unwrap(), 98.0);
+        assert_eq!(
+            Element::Ca.atomic_weight(Some(40)),
+            Err(Error::InvalidIsotope(Element::Ca, 40))
+        );
+    }
+
+    #[test]
+    fn test_valence_electrons() {
+        assert_eq!(Element::C.n_valence_electrons(0).unwrap(), 4);
+        assert_eq!(Element::C.n_valence_electrons(-1).unwrap(), 5);
+        assert_eq!(Element::O.n_valence_electrons(-2).unwrap(), 8);
+        assert_eq!(
+            Element::F.n_valence_electrons(-2),
+            Err(Error::InvalidFormalCharge(Element::F, -2))
+        )
+    }
+
+    #[test]
+    fn test_valence() {
+        assert_eq!(Element::C.valence(0).unwrap(), 4);
+        assert_eq!(Element::S.valence(0).unwrap(), 2);
+        assert_eq!(
+            Element::Na.n_valence_electrons(0),
+            Err(Error::UnsupportedElement(Element::Na))
+        );
+        assert_eq!(
+            Element::Fe.valence(0),
+            Err(Error::UnsupportedElement(Element::Fe))
+        );
+    }
+
+    #[test]
+    fn test_van_der_waals_radius() {
+        assert_eq!(Element::H.van_der_waals_radius(), Some(120.0));
+        assert_eq!(Element::C.van_der_waals_radius(), Some(170.0));
+        assert_eq!(Element::Og.van_der_waals_radius(), None);
+    }
+
+    #[test]
+    fn test_common_ion_charge() {
+        assert_eq!(Element::Na.common_ion_charge(), Some(1));
+        assert_eq!(Element::Cl.common_ion_charge(), Some(-1));
+        assert_eq!(Element::Ca.common_ion_charge(), Some(2));
+        assert_eq!(Element::Ne.common_ion_charge(), None);
+        assert_eq!(Element::Any.common_ion_charge(), None);
+    }
+
+    #[test]
+    fn test_metal_classification() {
+        assert!(Element::Fe.is_metal());
+        assert!(Element::Cl.is_nonmetal());
+        assert!(Element::Si.is_metalloid());
+        assert!(!Element::Any.is_metal());
+        assert!(!Element::Any.is_nonmetal());
+        assert!(!Element::Any.is_metalloid());
+
+        for atomic_number in 1..=118u8 {
+            let element = Element::try_from(atomic_number).unwrap();
+            let n_true = [
+                element.is_metal(),
+                element.is_nonmetal(),
+                element.is_metalloid(),
+            ]
+            .iter()
+            .filter(|&&b| b)
+            .count();
+            assert_eq!(n_true, 1, "{element}");
+        }
+    }
+
+    #[test]
+    fn test_simple_salt_formula() {
+        assert_eq!(
+            simple_salt_formula(Element::Na, Element::Cl),
+            Some("NaCl".to_owned())
+        );
+        assert_eq!(
+            simple_salt_formula(Element::Ca, Element::Cl),
+            Some("CaCl2".to_owned())
+        );
+        assert_eq!(
+            simple_salt_formula(Element::Al, Element::O),
+            Some("Al2O3".to_owned())
+        );
+        assert_eq!(simple_salt_formula(Element::Cl, Element::Na), None);
+        assert_eq!(simple_salt_formula(Element::Na, Element::Ne), None);
+    }
+
+    #[test]
+    fn test_family_predicates() {
+        assert!(!Element::H.is_alkali_metal());
+        assert!(Element::Na.is_alkali_metal());
+        assert!(Element::Ca.is_alkaline_earth_metal());
+        assert!(Element::Og.is_noble_gas());
+        assert!(Element::Ts.is_halogen());
+        assert!(!Element::Any.is_halogen());
+    }
+
+    #[test]
+    fn test_const_valence() {
+        static VALENCES: [Option<u8>; 3] = [
+            const_valence(Element::C),
+            const_valence(Element::N),
+            const_valence(Element::O),
+        ];
+        assert_eq!(VALENCES, [Some(4), Some(3), Some(2)]);
+        assert_eq!(const_valence(Element::Fe), None);
+
+        for element in [Element::H, Element::C, Element::S, Element::I] {
+            assert_eq!(const_valence(element), element.valence(0).ok());
+        }
+    }
+
+    #[test]
+    fn test_block_predicates() {
+        assert!(Element::Fe.is_transition_metal());
+        assert!(Element::Nd.is_lanthanide());
+        assert!(Element::U.is_actinide());
+        assert!(Element::La.is_lanthanide());
+        assert!(!Element::La.is_transition_metal());
+        assert!(!Element::Al.is_transition_metal());
+    }
+
+    #[test]
+    fn test_crustal_abundance_rank() {
+        assert_eq!(Element::O.crustal_abundance_rank(), Some(1));
+        assert_eq!(Element::Fe.crustal_abundance_rank(), Some(4));
+        assert_eq!(Element::Og.crustal_abundance_rank(), None);
+    }
+
+    #[test]
+    fn test_standard_state() {
+        assert_eq!(Element::Fe.standard_state(), Some(StandardState::Solid));
+        assert_eq!(Element::Og.standard_state(), None);
+        assert_eq!(Element::Any.standard_state(), None);
+
+        let mut liquids = vec![];
+        let mut gases = vec![];
+        for atomic_number in 1..=118u8 {
+            let element = Element::try_from(atomic_number).unwrap();
+            match element.standard_state() {
+                Some(StandardState::Liquid) => liquids.push(element),
+                Some(StandardState::Gas) => gases.push(element),
+                _ => (),
+            }
+        }
+        assert_eq!(liquids, vec![Element::Br, Element::Hg]);
+        assert_eq!(
+            gases,
+            vec![
+                Element::H,
+                Element::He,
+                Element::N,
+                Element::O,
+                Element::F,
+                Element::Ne,
+                Element::Cl,
+                Element::Ar,
+                Element::Kr,
+                Element::Xe,
+                Element::Rn,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_formula() {
+        assert_eq!(
+            parse_formula("CO"),
+            Ok(vec![(Element::C, 1), (Element::O, 1)])
+        );
+        assert_eq!(parse_formula("Co"), Ok(vec![(Element::Co, 1)]));
+        assert_eq!(
+            parse_formula("co"),
+            Err(Error::InvalidFormula("co".to_owned()))
+        );
+        assert_eq!(
+            parse_formula("CH3CH2OH"),
+            Ok(vec![(Element::C, 2), (Element::H, 6), (Element::O, 1)])
+        );
+        assert_eq!(
+            parse_formula("Xx2"),
+            Err(Error::InvalidAtomicSymbol("Xx".to_owned()))
+        );
+        assert_eq!(parse_formula(""), Err(Error::InvalidFormula("".to_owned())));
+    }
+
+    #[test]
+    fn test_phase_transitions() {
+        assert_eq!(Element::W.melting_point(), Some(3695.0));
+        assert_eq!(Element::He.melting_point(), None);
+        assert_eq!(Element::He.boiling_point(), Some(4.222));
+        assert_eq!(Element::Og.boiling_point(), None);
+    }
+
+    #[test]
+    fn test_catenation_tendency() {
+        assert_eq!(
+            Element::C.catenation_tendency(),
+            Some(CatenationLevel::High)
+        );
+        assert_eq!(
+            Element::Si.catenation_tendency(),
+            Some(CatenationLevel::Moderate)
+        );
+        assert_eq!(
+            Element::S.catenation_tendency(),
+            Some(CatenationLevel::Moderate)
+        );
+        assert_eq!(
+            Element::Ne.catenation_tendency(),
+            Some(CatenationLevel::None)
+        );
+        assert_eq!(Element::Na.catenation_tendency(), None);
+    }
+
+    #[test]
+    fn test_density() {
+        assert_eq!(Element::Os.density(), Some(22.59));
+        assert_eq!(Element::Og.density(), None);
+
+        for atomic_number in 1..=118u8 {
+            let element = Element::try_from(atomic_number).unwrap();
+            if let Some(density) = element.density() {
+                assert!(density <= 22.59, "{element}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_discovery_country() {
+        assert_eq!(Element::Tc.discovery_country(), Some("Italy"));
+        assert_eq!(Element::Fr.discovery_country(), Some("France"));
+        assert_eq!(Element::Pt.discovery_country(), Some("Colombia"));
+        assert_eq!(Element::Au.discovery_country(), None);
+    }
+
+    #[test]
+    fn test_electron_configuration() {
+        assert_eq!(Element::H.electron_configuration(), Some("1s1".to_owned()));
+        assert_eq!(
+            Element::Fe.electron_configuration(),
+            Some("1s2 2s2 2p6 3s2 3p6 3d6 4s2".to_owned())
+        );
+        assert_eq!(
+            Element::Cr.electron_configuration(),
+            Some("1s2 2s2 2p6 3s2 3p6 3d5 4s1".to_owned())
+        );
+        assert_eq!(Element::Any.electron_configuration(), None);
+
+        assert_eq!(
+            Element::He.electron_configuration_noble_gas_shorthand(),
+            Some("1s2".to_owned())
+        );
+        assert_eq!(
+            Element::Fe.electron_configuration_noble_gas_shorthand(),
+            Some("[Ar]3d6 4s2".to_owned())
+        );
+        assert_eq!(
+            Element::Cr.electron_configuration_noble_gas_shorthand(),
+            Some("[Ar]3d5 4s1".to_owned())
+        );
+        assert_eq!(
+            Element::Cu.electron_configuration_noble_gas_shorthand(),
+            Some("[Ar]3d10 4s1".to_owned())
+        );
+        assert_eq!(
+            Element::Pd.electron_configuration_noble_gas_shorthand(),
+            Some("[Kr]4d10".to_owned())
+        );
+        assert_eq!(
+            Element::Hg.electron_configuration_noble_gas_shorthand(),
+            Some("[Xe]4f14 5d10 6s2".to_owned())
+        );
+        assert_eq!(
+            Element::Ne.electron_configuration_noble_gas_shorthand(),
+            Some("[He]2s2 2p6".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_symbol_padded() {
+        assert_eq!(Element::H.symbol_padded(), "H ");
+        assert_eq!(Element::He.symbol_padded(), "He");
+        assert_eq!(Element::Any.symbol_padded(), "* ");
+    }
+
+    #[test]
+    fn test_first_ionization_energy() {
+        assert_eq!(Element::He.first_ionization_energy(), Some(2372.3));
+        assert_eq!(Element::Cs.first_ionization_energy(), Some(375.7));
+        assert_eq!(Element::Og.first_ionization_energy(), None);
+
+        for atomic_number in 1..=118u8 {
+            let element = Element::try_from(atomic_number).unwrap();
+            if let Some(energy) = element.first_ionization_energy() {
+                assert!((375.7..=2372.3).contains(&energy), "{element}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_platinum_group() {
+        assert!(Element::Pt.is_platinum_group());
+        assert!(Element::Pd.is_platinum_group());
+        assert!(!Element::Au.is_platinum_group());
+    }
+
+    #[test]
+    fn test_electron_affinity() {
+        assert_eq!(Element::Cl.electron_affinity(), Some(348.575));
+        assert_eq!(Element::Ne.electron_affinity(), None);
+        assert_eq!(Element::Mg.electron_affinity(), None);
+    }
+
+    #[test]
+    fn test_atom_economy() {
+        let economy = atom_economy(&["C2H4", "H2"], "C2H6").unwrap();
+        assert!((economy - 100.0).abs() < 1e-9);
+
+        let economy = atom_economy(&["CH4O", "CH2O2"], "C2H4O2").unwrap();
+        assert!((economy - 76.9).abs() < 0.1);
+
+        assert_eq!(
+            atom_economy(&["C2H4", "h2"], "C2H6"),
+            Err(Error::InvalidFormula("h2".to_owned()))
+        );
+        assert_eq!(
+            atom_economy(&[], "C2H6"),
+            Err(Error::InvalidFormula(String::new()))
+        );
+    }
+
+    #[test]
+    fn test_common_oxidation_states() {
+        assert_eq!(Element::Fe.common_oxidation_states(), &[2, 3]);
+        assert_eq!(Element::S.common_oxidation_states(), &[-2, 2, 4, 6]);
+        assert_eq!(Element::O.common_oxidation_states(), &[-2]);
+        assert!(Element::Any.common_oxidation_states().is_empty());
+
+        for atomic_number in 1..=118u8 {
+            let element = Element::try_from(atomic_number).unwrap();
+            let states = element.common_oxidation_states();
+            assert!(states.windows(2).all(|w| w[0] < w[1]), "{element}");
+        }
+    }
+
+    #[test]
+    fn test_typical_oxide_formula() {
+        assert_eq!(Element::Na.typical_oxide_formula(), Some("Na2O".to_owned()));
+        assert_eq!(Element::Mg.typical_oxide_formula(), Some("MgO".to_owned()));
+        assert_eq!(Element::C.typical_oxide_formula(), Some("CO2".to_owned()));
+        assert_eq!(Element::S.typical_oxide_formula(), Some("SO3".to_owned()));
+        assert_eq!(
+            Element::Al.typical_oxide_formula(),
+            Some("Al2O3".to_owned())
+        );
+        assert_eq!(Element::F.typical_oxide_formula(), None);
+        assert_eq!(Element::Xe.typical_oxide_formula(), None);
+    }
+
+    #[test]
+    fn test_valences() {
+        assert_eq!(Element::S.valences(0).unwrap(), &[2, 4, 6]);
+        assert_eq!(Element::P.valences(0).unwrap(), &[3, 5]);
+        assert_eq!(
+            Element::Fe.valences(0),
+            Err(Error::UnsupportedElement(Element::Fe))
+        );
+        assert_eq!(Element::C.valences(0).unwrap(), &[4]);
+        assert_eq!(Element::N.valences(1).unwrap(), &[4]);
+        assert_eq!(
+            Element::F.valences(-2),
+            Err(Error::InvalidFormalCharge(Element::F, -2))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_add_formulas() {
+        let counts = add_formulas("CH3", "OH").unwrap();
+        assert_eq!(
+            counts,
+            HashMap::from([(Element::C, 1), (Element::H, 4), (Element::O, 1)])
+        );
+        assert_eq!(
+            add_formulas(&format!("H{}", usize::MAX), "H"),
+            Err(Error::InvalidFormula(format!("H{}H", usize::MAX)))
+        );
+    }
+
+    #[test]
+    fn test_monoisotopic_mass() {
+        assert_eq!(Element::C.monoisotopic_mass().unwrap(), 12.000000);
+        assert_eq!(Element::Cl.monoisotopic_mass().unwrap(), 34.968853);
+        assert_eq!(Element::Fe.monoisotopic_mass().unwrap(), 55.934936);
+        assert_eq!(Element::Pb.monoisotopic_mass().unwrap(), 207.976652);
+        assert_eq!(
+            Element::Tc.monoisotopic_mass(),
+            Err(Error::UnsupportedElement(Element::Tc))
+        );
+        for atomic_number in 1..=118 {
+            let element = Element::try_from(atomic_number).unwrap();
+            let mass = element.monoisotopic_mass();
+            assert_eq!(mass.is_ok(), element.has_stable_isotope());
+            if let Ok(mass) = mass {
+                assert!((mass - element.atomic_weight(None).unwrap()).abs() < 2.5);
+            }
+        }
+        assert_eq!(
+            Element::Og.monoisotopic_mass(),
+            Err(Error::UnsupportedElement(Element::Og))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_subtract_formula() {
+        let counts = subtract_formula("C2H6O", "OH").unwrap();
+        assert_eq!(counts, HashMap::from([(Element::C, 2), (Element::H, 5)]));
+        assert_eq!(
+            subtract_formula("C2H6O", "O2"),
+            Err(Error::InvalidFormula("O2".to_owned()))
+        );
+        assert_eq!(
+            subtract_formula("C2H6O", "N"),
+            Err(Error::InvalidFormula("N".to_owned()))
+        );
+    }
+
+    #[test]
+    fn test_isotope_abundances() {
+        assert_eq!(
+            Element::Cl.isotope_abundances(),
+            &[(35, 0.7576), (37, 0.2424)]
+        );
+        for atomic_number in 1..=118u8 {
+            let element = Element::try_from(atomic_number).unwrap();
+            let abundances = element.isotope_abundances();
+            if !abundances.is_empty() {
+                let total: f64 = abundances.iter().map(|(_, fraction)| fraction).sum();
+                assert!((total - 1.0).abs() < 1e-6, "{element}");
+            }
+        }
+        assert!(Element::Og.isotope_abundances().is_empty());
+    }
+
+    #[test]
+    fn test_most_abundant_isotope() {
+        assert_eq!(Element::Cl.most_abundant_isotope(), Some(35));
+        assert_eq!(Element::Ar.most_abundant_isotope(), Some(40));
+        assert_eq!(Element::Og.most_abundant_isotope(), None);
+    }
+
+    #[test]
+    fn test_weights_revision() {
+        assert_eq!(WEIGHTS_SOURCE_YEAR, 2013);
+        assert_eq!(weights_revision(), WEIGHTS_SOURCE_YEAR);
+    }
+
+    #[test]
+    fn test_parse_formula_groups() {
+        assert_eq!(
+            parse_formula("Ca(OH)2"),
+            Ok(vec![(Element::Ca, 1), (Element::O, 2), (Element::H, 2)])
+        );
+        assert_eq!(
+            parse_formula("K4(Fe(CN)6)"),
+            Ok(vec![
+                (Element::K, 4),
+                (Element::Fe, 1),
+                (Element::C, 6),
+                (Element::N, 6)
+            ])
+        );
+        for formula in ["Ca(OH", "CaOH)2", "Ca()2"] {
+            assert_eq!(
+                parse_formula(formula),
+                Err(Error::InvalidFormula(formula.to_owned()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_molecular_weight() {
+        assert!((molecular_weight("H2O").unwrap() - 18.015).abs() < 1e-3);
+        assert!((molecular_weight("C6H12O6").unwrap() - 180.16).abs() < 1e-2);
+        assert!((molecular_weight("Ca(OH)2").unwrap() - 74.09).abs() < 1e-2);
+        assert_eq!(
+            molecular_weight("Xx"),
+            Err(Error::InvalidAtomicSymbol("Xx".to_owned()))
+        );
+    }
+
+    #[test]
+    fn test_simple_color_class() {
+        assert_eq!(Element::Fe.simple_color_class(), SimpleColorClass::Metal);
+        assert_eq!(
+            Element::Si.simple_color_class(),
+            SimpleColorClass::Metalloid
+        );
+        assert_eq!(Element::Cl.simple_color_class(), SimpleColorClass::Nonmetal);
+        assert_eq!(Element::Og.simple_color_class(), SimpleColorClass::Unknown);
+        assert_eq!(Element::Any.simple_color_class(), SimpleColorClass::Unknown);
+    }
+
+    #[test]
+    fn test_const_atomic_number() {
+        const OXYGEN: Option<Element> = Element::from_atomic_number(8);
+        const OXYGEN_NUMBER: u8 = Element::O.as_atomic_number();
+        assert_eq!(OXYGEN, Some(Element::O));
+        assert_eq!(OXYGEN_NUMBER, 8);
+        assert_eq!(Element::from_atomic_number(119), None);
+
+        for atomic_number in 0..=118u8 {
+            let element = Element::from_atomic_number(atomic_number).unwrap();
+            assert_eq!(element.as_atomic_number(), atomic_number);
+            assert_eq!(u8::from(element), atomic_number);
+        }
+    }
+
+    #[test]
+    fn test_atomic_number_radix() {
+        assert_eq!(Element::C.atomic_number_radix(2), "110");
+        assert_eq!(Element::C.atomic_number_radix(16), "6");
+        assert_eq!(Element::Fe.atomic_number_radix(16), "1a");
+        assert_eq!(Element::Any.atomic_number_radix(2), "0");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_atomic_number_radix_invalid() {
+        Element::C.atomic_number_radix(37);
+    }
+
+    #[test]
+    fn test_preceding_noble_gas() {
+        assert_eq!(Element::Na.preceding_noble_gas(), Some(Element::Ne));
+        assert_eq!(Element::Fe.preceding_noble_gas(), Some(Element::Ar));
+        assert_eq!(Element::C.preceding_noble_gas(), Some(Element::He));
+        assert_eq!(Element::Og.preceding_noble_gas(), Some(Element::Rn));
+        assert_eq!(Element::He.preceding_noble_gas(), None);
+        assert_eq!(Element::H.preceding_noble_gas(), None);
+    }
+
+    #[test]
+    fn test_symbol() {
+        assert_eq!(Element::C.symbol(), "C");
+        assert_eq!(Element::He.symbol(), "He");
+        assert_eq!(Element::Any.symbol(), "*");
+
+        let symbol: &'static str = Element::C.symbol();
+        assert!(core::ptr::eq(symbol, Element::C.symbol()));
+        for atomic_number in 0..=118u8 {
+            let element = Element::try_from(atomic_number).unwrap();
+            assert_eq!(element.symbol(), element.atomic_symbol());
+        }
+    }
+
+    #[test]
+    fn test_atom_percent() {
+        let fraction = atom_percent("H2O", Element::H).unwrap();
+        assert!((fraction - 2.0 / 3.0).abs() < 1e-9);
+        assert_eq!(atom_percent("H2O", Element::C).unwrap(), 0.0);
+        assert_eq!(
+            atom_percent("h2o", Element::H),
+            Err(Error::InvalidFormula("h2o".to_owned()))
+        );
+    }
+
+    #[test]
+    fn test_weight_matches() {
+        assert!(Element::C.weight_matches(12.011, 100.0).unwrap());
+        assert!(!Element::C.weight_matches(13.0, 100.0).unwrap());
+        assert!(!Element::C.weight_matches(12.011, 10.0).unwrap());
+    }
+
+    #[test]
+    fn test_from_smiles_symbol() {
+        assert_eq!(Element::from_smiles_symbol("c"), Ok((Element::C, true)));
+        assert_eq!(Element::from_smiles_symbol("C"), Ok((Element::C, false)));
+        assert_eq!(Element::from_smiles_symbol("se"), Ok((Element::Se, true)));
+        assert_eq!(Element::from_smiles_symbol("Cl"), Ok((Element::Cl, false)));
+        assert_eq!(
+            Element::from_smiles_symbol("cl"),
+            Err(Error::InvalidAtomicSymbol("cl".to_owned()))
+        );
+        assert_eq!(
+            Element::from_smiles_symbol("CL"),
+            Err(Error::InvalidAtomicSymbol("CL".to_owned()))
+        );
+    }
+
+    #[test]
+    fn test_common_hybridizations() {
+        assert_eq!(Element::C.common_hybridizations(), &["sp", "sp2", "sp3"]);
+        assert_eq!(
+            Element::S.common_hybridizations(),
+            &["sp3", "sp3d", "sp3d2"]
+        );
+        assert!(Element::Fe.common_hybridizations().is_empty());
+    }
+
+    #[test]
+    fn test_is_aromatic_organic_subset() {
+        for element in [
+            Element::C,
+            Element::N,
+            Element::O,
+            Element::S,
+            Element::Se,
+            Element::As,
+            Element::B,
+            Element::P,
+        ] {
+            assert!(element.is_aromatic_organic_subset());
+        }
+        assert!(!Element::F.is_aromatic_organic_subset());
+        assert!(!Element::Fe.is_aromatic_organic_subset());
+    }
+
+    #[test]
+    fn test_atom_validate() {
+        let atom = Atom {
+            element: Element::C,
+            isotope: Some(13),
+            formal_charge: -1,
+        };
+        assert_eq!(atom.validate(), Ok(()));
+
+        let atom = Atom {
+            element: Element::C,
+            isotope: Some(99),
+            formal_charge: 0,
+        };
+        assert_eq!(atom.validate(), Err(Error::InvalidIsotope(Element::C, 99)));
+
+        let atom = Atom {
+            element: Element::H,
+            isotope: None,
+            formal_charge: 2,
+        };
+        assert_eq!(
+            atom.validate(),
+            Err(Error::InvalidFormalCharge(Element::H, 2))
+        );
+    }
+
+    #[test]
+    fn test_is_organic_subset() {
+        let organic_subset = [
+            Element::B,
+            Element::C,
+            Element::N,
+            Element::O,
+            Element::P,
+            Element::S,
+            Element::F,
+            Element::Cl,
+            Element::Br,
+            Element::I,
+        ];
+        for atomic_number in 0..=118u8 {
+            let element = Element::try_from(atomic_number).unwrap();
+            assert_eq!(
+                element.is_organic_subset(),
+                organic_subset.contains(&element)
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_symbols() {
+        let (elements, failures) = parse_symbols("C  Xx\tO\nna Qq *");
+        assert_eq!(
+            elements,
+            vec![Element::C, Element::O, Element::Na, Element::Any]
+        );
+        assert_eq!(failures, vec![(1, "Xx".to_owned()), (4, "Qq".to_owned())]);
+
+        assert_eq!(parse_symbols(""), (vec![], vec![]));
+    }
+
+    #[test]
+    fn test_known_isotopes() {
+        assert_eq!(Element::C.known_isotopes(), &[12, 13, 14]);
+        assert_eq!(Element::H.known_isotopes(), &[1, 2, 3]);
+        assert!(Element::Fe.known_isotopes().is_empty());
+
+        for atomic_number in 1..=118u8 {
+            let element = Element::try_from(atomic_number).unwrap();
+            for isotope in 0..=300 {
+                assert_eq!(
+                    element.atomic_weight(Some(isotope)).is_ok(),
+                    element.known_isotopes().contains(&isotope)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_redox_tendency() {
+        assert_eq!(
+            Element::F.redox_tendency(),
+            Some(RedoxTendency::StrongOxidizer)
+        );
+        assert_eq!(Element::Br.redox_tendency(), Some(RedoxTendency::Oxidizer));
+        assert_eq!(Element::Zn.redox_tendency(), Some(RedoxTendency::Reducer));
+        assert_eq!(
+            Element::Na.redox_tendency(),
+            Some(RedoxTendency::StrongReducer)
+        );
+        assert_eq!(Element::Ne.redox_tendency(), None);
+        assert_eq!(Element::Any.redox_tendency(), None);
+    }
+
+    #[test]
+    fn test_isotope_mass() {
+        let deuterium = Isotope {
+            element: Element::H,
+            mass_number: 2,
+        };
+        assert_eq!(deuterium, Isotope::DEUTERIUM);
+        assert_eq!(deuterium.mass(), Ok(2.014_102));
+        assert_eq!(Isotope::CARBON_13.mass(), Ok(13.003_355));
+        assert_eq!(
+            Isotope {
+                element: Element::C,
+                mass_number: 99,
+            }
+            .mass(),
+            Err(Error::InvalidIsotope(Element::C, 99))
+        );
+    }
+
+    #[test]
+    fn test_cpk_color() {
+        assert_eq!(Element::H.cpk_color(), [255, 255, 255]);
+        assert_eq!(Element::C.cpk_color(), [144, 144, 144]);
+        assert_eq!(Element::O.cpk_color(), [255, 13, 13]);
+        assert_eq!(Element::N.cpk_color(), [48, 80, 248]);
+        assert_eq!(Element::Any.cpk_color(), [255, 20, 147]);
+        assert_eq!(Element::Og.cpk_color(), [255, 20, 147]);
+    }
+
+    #[test]
+    fn test_principal_decay_mode() {
+        assert_eq!(Element::U.principal_decay_mode(), Some("alpha"));
+        assert_eq!(Element::Tc.principal_decay_mode(), Some("beta-"));
+        assert_eq!(Element::Pm.principal_decay_mode(), Some("electron capture"));
+        assert_eq!(Element::C.principal_decay_mode(), None);
+        assert_eq!(Element::Pb.principal_decay_mode(), None);
+        assert_eq!(Element::Any.principal_decay_mode(), None);
+    }
+
+    #[test]
+    fn test_try_from_str() {
+        assert_eq!(Element::try_from("Fe"), Ok(Element::Fe));
+        assert_eq!(Element::try_from("Fe".to_owned()), Ok(Element::Fe));
+        assert_eq!(
+            Element::try_from("Xx"),
+            Err(Error::InvalidAtomicSymbol("Xx".to_owned()))
+        );
+        assert_eq!(
+            Element::try_from("Xx".to_owned()),
+            Err(Error::InvalidAtomicSymbol("Xx".to_owned()))
+        );
+    }
+
+    #[test]
+    fn test_valence_shell_n() {
+        assert_eq!(Element::H.valence_shell_n(), Some(1));
+        assert_eq!(Element::C.valence_shell_n(), Some(2));
+        assert_eq!(Element::Na.valence_shell_n(), Some(3));
+        assert_eq!(Element::K.valence_shell_n(), Some(4));
+        assert_eq!(Element::Fe.valence_shell_n(), Some(4));
+        assert_eq!(Element::Pd.valence_shell_n(), Some(4));
+        assert_eq!(Element::U.valence_shell_n(), Some(7));
+        assert_eq!(Element::Og.valence_shell_n(), Some(7));
+        assert_eq!(Element::Any.valence_shell_n(), None);
+    }
+
+    #[test]
+    fn test_try_from_u16_u32() {
+        assert_eq!(Element::try_from(8u16), Ok(Element::O));
+        assert_eq!(Element::try_from(118u32), Ok(Element::Og));
+        assert_eq!(
+            Element::try_from(119u16),
+            Err(Error::InvalidAtomicNumber(119))
+        );
+        assert_eq!(
+            Element::try_from(374u16),
+            Err(Error::InvalidAtomicNumber(u8::MAX))
+        );
+        assert_eq!(
+            Element::try_from(262u32),
+            Err(Error::InvalidAtomicNumber(u8::MAX))
+        );
+        assert_eq!(
+            Element::try_from(-1),
+            Err(Error::InvalidAtomicNumber(u8::MAX))
+        );
+    }
+
+    #[test]
+    fn test_period() {
+        assert_eq!(Element::Any.period(), None);
+        assert_eq!(Element::H.period(), Some(1));
+        assert_eq!(Element::He.period(), Some(1));
+        assert_eq!(Element::Li.period(), Some(2));
+        assert_eq!(Element::Ar.period(), Some(3));
+        assert_eq!(Element::K.period(), Some(4));
+        assert_eq!(Element::Xe.period(), Some(5));
+        assert_eq!(Element::Lu.period(), Some(6));
+        assert_eq!(Element::Og.period(), Some(7));
+    }
+
+    #[test]
+    fn test_group() {
+        assert_eq!(Element::Any.group(), None);
+        assert_eq!(Element::H.group(), Some(1));
+        assert_eq!(Element::He.group(), Some(18));
+        assert_eq!(Element::B.group(), Some(13));
+        assert_eq!(Element::Fe.group(), Some(8));
+        assert_eq!(Element::Ba.group(), Some(2));
+        assert_eq!(Element::La.group(), None);
+        assert_eq!(Element::Hf.group(), Some(4));
+        assert_eq!(Element::U.group(), None);
+        assert_eq!(Element::Og.group(), Some(18));
+
+        for atomic_number in 1..=118u8 {
+            let element = Element::try_from(atomic_number).unwrap();
+            assert_eq!(
+                element.group().is_none(),
+                element.is_lanthanide() || element.is_actinide()
+            );
+        }
+    }
+
+    #[test]
+    fn test_column_major_key() {
+        let mut elements = vec![Element::Cl, Element::Na, Element::F, Element::Li];
+        elements.sort_by_key(|element| element.column_major_key());
+        assert_eq!(
+            elements,
+            vec![Element::Li, Element::Na, Element::F, Element::Cl]
+        );
+
+        assert_eq!(Element::Na.column_major_key(), Some((1, 3)));
+        assert_eq!(Element::Ce.column_major_key(), None);
+        assert_eq!(Element::Any.column_major_key(), None);
+    }
+
+    #[test]
+    fn test_mendeleev_number() {
+        assert_eq!(Element::He.mendeleev_number(), Some(1));
+        assert_eq!(Element::H.mendeleev_number(), Some(103));
+        assert_eq!(Element::Any.mendeleev_number(), None);
+        assert_eq!(Element::Rf.mendeleev_number(), None);
+
+        // neighbors on the scale are chemically similar
+        assert_eq!(Element::Na.mendeleev_number(), Some(11));
+        assert_eq!(Element::Li.mendeleev_number(), Some(12));
+        assert_eq!(Element::Cl.mendeleev_number(), Some(99));
+        assert_eq!(Element::Br.mendeleev_number(), Some(98));
+
+        let mut numbers = (1..=103u8)
+            .map(|n| Element::try_from(n).unwrap().mendeleev_number().unwrap())
+            .collect::<Vec<_>>();
+        numbers.sort();
+        assert_eq!(numbers, (1..=103).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_error_element() {
+        match Element::C.atomic_weight(Some(99)) {
+            Err(Error::InvalidIsotope(element, isotope)) => {
+                assert_eq!(element, Element::C);
+                assert_eq!(isotope, 99);
+            }
+            _ => panic!("expected Error::InvalidIsotope"),
+        }
+        match Element::F.n_valence_electrons(-2) {
+            Err(Error::InvalidFormalCharge(element, formal_charge)) => {
+                assert_eq!(element, Element::F);
+                assert_eq!(formal_charge, -2);
+            }
+            _ => panic!("expected Error::InvalidFormalCharge"),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_error_source() {
+        use std::error::Error as _;
+
+        assert!(Error::InvalidIsotope(Element::C, 99).source().is_none());
+    }
+
+    #[test]
+    fn test_matches() {
+        assert!(Element::Any.matches(&Element::C));
+        assert!(Element::C.matches(&Element::Any));
+        assert!(Element::Any.matches(&Element::Any));
+        assert!(Element::C.matches(&Element::C));
+        assert!(!Element::C.matches(&Element::O));
+    }
+
+    #[test]
+    fn test_atomic_weight_uncertainty() {
+        assert_eq!(Element::H.atomic_weight_uncertainty(None), Ok(0.000_135));
+        assert_eq!(Element::S.atomic_weight_uncertainty(None), Ok(0.008_5));
+        assert_eq!(Element::Fe.atomic_weight_uncertainty(None), Ok(0.002));
+        assert_eq!(
+            Element::H.atomic_weight_uncertainty(Some(2)),
+            Ok(0.000_000_5)
+        );
+        assert_eq!(Element::Any.atomic_weight_uncertainty(None), Ok(0.0));
+        assert_eq!(
+            Element::Tc.atomic_weight_uncertainty(None),
+            Err(Error::UnsupportedElement(Element::Tc))
+        );
+        assert_eq!(
+            Element::C.atomic_weight_uncertainty(Some(99)),
+            Err(Error::InvalidIsotope(Element::C, 99))
+        );
+    }
+
+    #[test]
+    fn test_electronegativity() {
+        assert_eq!(Element::F.electronegativity(), Some(3.98));
+        assert_eq!(Element::C.electronegativity(), Some(2.55));
+        assert_eq!(Element::Ne.electronegativity(), None);
+        assert_eq!(Element::Any.electronegativity(), None);
+    }
+
+    #[test]
+    fn test_covalent_radius() {
+        assert_eq!(Element::H.covalent_radius(), Some(31.0));
+        assert_eq!(Element::C.covalent_radius(), Some(76.0));
+        assert_eq!(Element::Fr.covalent_radius(), Some(260.0));
+        assert_eq!(Element::Og.covalent_radius(), None);
+    }
+
+    #[test]
+    fn test_feature_vector() {
+        let features = Element::C.feature_vector();
+        assert_eq!(features.len(), 6);
+        assert_eq!(features[0], 6.0 / 118.0);
+        assert_eq!(features[3], 14.0 / 18.0);
+        assert_eq!(features[4], 2.0 / 7.0);
+        assert_eq!(features[5], 4.0 / 8.0);
+        assert_eq!(Element::Ne.feature_vector()[5], 1.0);
+        assert_eq!(Element::Fe.feature_vector()[5], 2.0 / 8.0);
+        assert_eq!(Element::Any.feature_vector(), [0.0; 6]);
+
+        for atomic_number in 1..=118u8 {
+            let element = Element::try_from(atomic_number).unwrap();
+            assert!(element
+                .feature_vector()
+                .iter()
+                .all(|feature| (0.0..=1.0).contains(feature)));
+        }
+    }
+
+    #[test]
+    fn test_atomic_weight_interval() {
+        assert_eq!(
+            Element::C.atomic_weight_interval(),
+            Some((12.009_6, 12.011_6))
+        );
+        assert_eq!(Element::Fe.atomic_weight_interval(), None);
+        assert_eq!(Element::Any.atomic_weight_interval(), None);
+
+        for atomic_number in 1..=118u8 {
+            let element = Element::try_from(atomic_number).unwrap();
+            if let Some((lower, upper)) = element.atomic_weight_interval() {
+                let weight = element.atomic_weight(None).unwrap();
+                assert!(lower <= weight && weight <= upper);
+            }
+        }
+        assert_eq!(
+            (1..=118u8)
+                .filter_map(|n| Element::try_from(n).unwrap().atomic_weight_interval())
+                .count(),
+            14
+        );
+    }
+
+    #[test]
+    fn test_group_name() {
+        assert_eq!(Element::Cl.group_name(), Some("Halogens"));
+        assert_eq!(Element::Ne.group_name(), Some("Noble gases"));
+        assert_eq!(Element::Na.group_name(), Some("Alkali metals"));
+        assert_eq!(Element::Mg.group_name(), Some("Alkaline earth metals"));
+        assert_eq!(Element::Fe.group_name(), Some("Transition metals"));
+        assert_eq!(Element::La.group_name(), Some("Lanthanides"));
+        assert_eq!(Element::U.group_name(), Some("Actinides"));
+        assert_eq!(Element::Al.group_name(), Some("Boron group"));
+        assert_eq!(Element::Si.group_name(), Some("Carbon group"));
+        assert_eq!(Element::N.group_name(), Some("Pnictogens"));
+        assert_eq!(Element::S.group_name(), Some("Chalcogens"));
+        assert_eq!(Element::H.group_name(), None);
+        assert_eq!(Element::Any.group_name(), None);
+    }
+
+    #[test]
+    fn test_swappable_symbol() {
+        let pairs = [
+            (Element::Al, Element::La),
+            (Element::Ar, Element::Ra),
+            (Element::Ca, Element::Ac),
+            (Element::Sc, Element::Cs),
+            (Element::Ni, Element::In),
+            (Element::Ga, Element::Ag),
+            (Element::Se, Element::Es),
+            (Element::Br, Element::Rb),
+            (Element::Er, Element::Re),
+            (Element::Ta, Element::At),
+            (Element::Cm, Element::Mc),
+            (Element::Fr, Element::Rf),
+            (Element::Tm, Element::Mt),
+        ];
+        for (a, b) in pairs {
+            assert_eq!(a.swappable_symbol(), Some(b));
+            assert_eq!(b.swappable_symbol(), Some(a));
+        }
+
+        let splits = [
+            (Element::Si, Element::S),
+            (Element::Co, Element::C),
+            (Element::Cu, Element::C),
+            (Element::Nb, Element::N),
+            (Element::Sn, Element::S),
+            (Element::Sb, Element::S),
+            (Element::Ho, Element::H),
+            (Element::Yb, Element::Y),
+            (Element::Hf, Element::H),
+            (Element::Os, Element::O),
+            (Element::Pb, Element::P),
+            (Element::Bi, Element::B),
+            (Element::Po, Element::P),
+            (Element::Np, Element::N),
+            (Element::Pu, Element::P),
+            (Element::Bk, Element::B),
+            (Element::Cf, Element::C),
+            (Element::No, Element::N),
+            (Element::Bh, Element::B),
+            (Element::Hs, Element::H),
+            (Element::Cn, Element::C),
+            (Element::Nh, Element::N),
+        ];
+        for (element, head) in splits {
+            assert_eq!(element.swappable_symbol(), Some(head));
+        }
+        assert_eq!(
+            (1..=118u8)
+                .filter(|&n| Element::try_from(n).unwrap().swappable_symbol().is_some())
+                .count(),
+            2 * pairs.len() + splits.len()
+        );
+
+        assert_eq!(Element::Xe.swappable_symbol(), None);
+        assert_eq!(Element::Fe.swappable_symbol(), None);
+        assert_eq!(Element::C.swappable_symbol(), None);
+        assert_eq!(Element::Any.swappable_symbol(), None);
+    }
+
+    #[test]
+    fn test_heavier_of() {
+        assert_eq!(heavier_of(Element::H, Element::O), Element::O);
+        assert_eq!(heavier_of(Element::O, Element::H), Element::O);
+        assert_eq!(heavier_of(Element::Any, Element::H), Element::H);
+        assert_eq!(heavier_of(Element::C, Element::C), Element::C);
+    }
+
+    #[test]
+    fn test_heaviest() {
+        assert_eq!(
+            heaviest(&[Element::H, Element::O, Element::C]),
+            Some(Element::O)
+        );
+        assert_eq!(heaviest(&[Element::Fe]), Some(Element::Fe));
+        assert_eq!(heaviest(&[]), None);
+    }
+
+    #[test]
+    fn test_is_real() {
+        assert!(!Element::Any.is_real());
+        assert!(Element::H.is_real());
+        assert!(Element::Og.is_real());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for atomic_number in 0..=118u8 {
+            let element = Element::try_from(atomic_number).unwrap();
+            assert_eq!(element.is_real(), atomic_number != 0);
+            assert_eq!(Element::try_from(element.atomic_number()), Ok(element));
+            assert_eq!(element.symbol().parse::<Element>(), Ok(element));
+            assert_eq!(element.to_string().parse::<Element>(), Ok(element));
+        }
+    }
+
+    #[test]
+    fn test_any() {
+        let any = Element::Any;
+        assert_eq!(any.atomic_number(), 0);
+        assert_eq!(any.symbol(), "*");
+        assert_eq!(any.atomic_weight(None), Ok(0.0));
+        assert_eq!(
+            any.n_valence_electrons(0),
+            Err(Error::UnsupportedElement(Element::Any))
+        );
+        assert_eq!(any.valence(0), Err(Error::UnsupportedElement(Element::Any)));
+        assert_eq!(
+            any.valences(0),
+            Err(Error::UnsupportedElement(Element::Any))
+        );
+        assert_eq!(any.period(), None);
+        assert_eq!(any.group(), None);
+        assert_eq!(any.electron_configuration(), None);
+        assert_eq!(any.typical_oxide_formula(), None);
+        assert_eq!(any.monoisotopic_mass(), Ok(0.0));
+        assert_eq!(any.most_abundant_isotope(), None);
+        assert_eq!(any.common_oxidation_states(), &[] as &[i8]);
+        assert_eq!(any.preceding_noble_gas(), None);
+        assert!(!any.is_metal() && !any.is_nonmetal() && !any.is_metalloid());
+        assert!(any.known_isotopes().is_empty());
+    }
+
+    #[test]
+    fn test_nominal_mass() {
+        assert_eq!(Element::Cl.nominal_mass(), Some(35));
+        assert_eq!(Element::C.nominal_mass(), Some(12));
+        assert_eq!(Element::Br.nominal_mass(), Some(79));
+        assert_eq!(Element::Fe.nominal_mass(), None);
+        assert_eq!(Element::Any.nominal_mass(), None);
+    }
 
-        let error = Error::InvalidIsotope("C".to_owned(), 10);
-        assert_eq!(format!("{error}"), "invalid isotope 10 for C");
+    #[test]
+    fn test_atomic_radius() {
+        assert_eq!(Element::C.atomic_radius(), Some(70.0));
+        assert_eq!(Element::H.atomic_radius(), Some(25.0));
+        assert_eq!(Element::Cs.atomic_radius(), Some(260.0));
+        assert_eq!(Element::Ne.atomic_radius(), None);
+        assert_eq!(Element::Any.atomic_radius(), None);
+    }
 
-        let error = Error::InvalidFormalCharge("O".to_owned(), -3);
-        assert_eq!(format!("{error}"), "invalid formal charge -3 for O");
+    #[test]
+    fn test_parse_bracket_element() {
+        assert_eq!(parse_bracket_element("13C"), Ok((Element::C, Some(13))));
+        assert_eq!(parse_bracket_element("Fe"), Ok((Element::Fe, None)));
+        assert_eq!(parse_bracket_element("2H"), Ok((Element::H, Some(2))));
+        assert_eq!(parse_bracket_element("*"), Ok((Element::Any, None)));
+        assert_eq!(
+            parse_bracket_element("13"),
+            Err(Error::InvalidAtomicSymbol("13".to_owned()))
+        );
+        assert_eq!(
+            parse_bracket_element("99999C"),
+            Err(Error::InvalidAtomicSymbol("99999C".to_owned()))
+        );
+        assert_eq!(
+            parse_bracket_element("13Xx"),
+            Err(Error::InvalidAtomicSymbol("13Xx".to_owned()))
+        );
     }
 
     #[test]
-    fn test_try_from_atomic_number() {
-        assert_eq!(Element::H, Element::try_from(1).unwrap());
-        assert_eq!(Element::C, Element::try_from(6).unwrap());
-        assert_eq!(Err(Error::InvalidAtomicNumber(200)), Element::try_from(200));
+    fn test_implicit_hydrogens() {
+        assert_eq!(Element::C.implicit_hydrogens(0, 3), Ok(1));
+        assert_eq!(Element::C.implicit_hydrogens(0, 0), Ok(4));
+        assert_eq!(Element::N.implicit_hydrogens(0, 3), Ok(0));
+        assert_eq!(Element::N.implicit_hydrogens(0, 4), Ok(1));
+        assert_eq!(Element::N.implicit_hydrogens(1, 4), Ok(0));
+        assert_eq!(Element::S.implicit_hydrogens(0, 3), Ok(1));
+        assert_eq!(Element::Cl.implicit_hydrogens(0, 2), Ok(0));
+        assert_eq!(
+            Element::F.implicit_hydrogens(-2, 0),
+            Err(Error::InvalidFormalCharge(Element::F, -2))
+        );
+        assert_eq!(
+            Element::Fe.implicit_hydrogens(0, 2),
+            Err(Error::UnsupportedElement(Element::Fe))
+        );
     }
 
     #[test]
-    fn test_atomic_number() {
-        assert_eq!(Element::H.atomic_number(), 1);
-        assert_eq!(Element::C.atomic_number(), 6);
+    fn test_has_stable_isotope() {
+        assert!(Element::Mo.has_stable_isotope());
+        assert!(!Element::Tc.has_stable_isotope());
+        assert!(Element::Ru.has_stable_isotope());
+        assert!(Element::Nd.has_stable_isotope());
+        assert!(!Element::Pm.has_stable_isotope());
+        assert!(Element::Sm.has_stable_isotope());
+        assert!(Element::Pb.has_stable_isotope());
+        assert!(!Element::Bi.has_stable_isotope());
+        assert!(!Element::U.has_stable_isotope());
+        assert!(!Element::Any.has_stable_isotope());
     }
 
     #[test]
-    fn test_from_atomic_symbol() {
-        assert_eq!(Element::H, "H".parse().unwrap());
-        assert_eq!(Element::C, "c".parse().unwrap());
+    fn test_is_radioactive() {
+        assert!(Element::Tc.is_radioactive());
+        assert!(Element::U.is_radioactive());
+        assert!(!Element::C.is_radioactive());
+        assert!(!Element::K.is_radioactive());
+        assert!(!Element::Any.is_radioactive());
+
+        for atomic_number in 1..=118u8 {
+            let element = Element::try_from(atomic_number).unwrap();
+            assert_ne!(element.is_radioactive(), element.has_stable_isotope());
+            assert_eq!(
+                element.is_radioactive(),
+                element.principal_decay_mode().is_some()
+            );
+        }
+    }
+
+    #[test]
+    fn test_labeled_molecular_weight() {
+        let weight = labeled_molecular_weight("[13C]6H12O6").unwrap();
+        let expected = 6.0 * 13.003_355 + 12.0 * 1.007_975 + 6.0 * 15.9994;
+        assert!((weight - expected).abs() < 1e-9);
+        assert!(
+            (labeled_molecular_weight("C6H12O6").unwrap() - molecular_weight("C6H12O6").unwrap())
+                .abs()
+                < 1e-9
+        );
+        let weight = labeled_molecular_weight("C[13C]H3[2H]3").unwrap();
+        let expected = 12.0106 + 13.003_355 + 3.0 * 1.007_975 + 3.0 * 2.014_102;
+        assert!((weight - expected).abs() < 1e-9);
         assert_eq!(
-            Err(Error::InvalidAtomicSymbol("A".to_owned())),
-            "A".parse::<Element>()
+            labeled_molecular_weight("[99C]H4"),
+            Err(Error::InvalidIsotope(Element::C, 99))
+        );
+        assert_eq!(
+            labeled_molecular_weight("[C]H4"),
+            Err(Error::InvalidFormula("[C]H4".to_owned()))
+        );
+        assert_eq!(
+            labeled_molecular_weight("[13C H4"),
+            Err(Error::InvalidFormula("[13C H4".to_owned()))
+        );
+        assert_eq!(
+            parse_formula("[13C]6"),
+            Err(Error::InvalidFormula("[13C]6".to_owned()))
         );
     }
 
     #[test]
-    fn test_atomic_symbol() {
-        assert_eq!(Element::H.atomic_symbol(), "H".to_owned());
-        assert_eq!(Element::C.atomic_symbol(), "C".to_owned());
+    fn test_from_atomic_number_strict() {
+        assert_eq!(
+            Element::from_atomic_number_strict(0),
+            Err(Error::InvalidAtomicNumber(0))
+        );
+        assert_eq!(Element::try_from(0u8), Ok(Element::Any));
+        assert_eq!(Element::from_atomic_number_strict(6), Ok(Element::C));
+        assert_eq!(Element::from_atomic_number_strict(118), Ok(Element::Og));
+        assert_eq!(
+            Element::from_atomic_number_strict(119),
+            Err(Error::InvalidAtomicNumber(119))
+        );
     }
 
     #[test]
-    fn test_to_str() {
-        assert_eq!(format!("{}", Element::H), "H");
+    fn test_from_period_group() {
+        assert_eq!(Element::from_period_group(4, 11), Some(Element::Cu));
+        assert_eq!(Element::from_period_group(1, 1), Some(Element::H));
+        assert_eq!(Element::from_period_group(1, 18), Some(Element::He));
+        assert_eq!(Element::from_period_group(7, 18), Some(Element::Og));
+        assert_eq!(Element::from_period_group(1, 2), None);
+        assert_eq!(Element::from_period_group(2, 5), None);
+        assert_eq!(Element::from_period_group(6, 3), None);
+        assert_eq!(Element::from_period_group(8, 1), None);
+
+        for atomic_number in 1..=118u8 {
+            let element = Element::try_from(atomic_number).unwrap();
+            if let Some((group, period)) = element.column_major_key() {
+                assert_eq!(Element::from_period_group(period, group), Some(element));
+            }
+        }
+    }
+
+    #[test]
+    fn test_next_in_group() {
+        assert_eq!(Element::Li.next_in_group(), Some(Element::Na));
+        assert_eq!(Element::H.next_in_group(), Some(Element::Li));
+        assert_eq!(Element::Y.next_in_group(), None);
+        assert_eq!(Element::Fr.next_in_group(), None);
+        assert_eq!(Element::Ce.next_in_group(), None);
+        assert_eq!(Element::Any.next_in_group(), None);
+    }
+
+    #[test]
+    fn test_prev_in_group() {
+        assert_eq!(Element::Na.prev_in_group(), Some(Element::Li));
+        assert_eq!(Element::Ne.prev_in_group(), Some(Element::He));
+        assert_eq!(Element::B.prev_in_group(), None);
+        assert_eq!(Element::H.prev_in_group(), None);
+    }
+
+    #[test]
+    fn test_next_in_period() {
+        assert_eq!(Element::Ne.next_in_period(), None);
+        assert_eq!(Element::Li.next_in_period(), Some(Element::Be));
+        assert_eq!(Element::Be.next_in_period(), Some(Element::B));
+        assert_eq!(Element::H.next_in_period(), Some(Element::He));
+        assert_eq!(Element::Ba.next_in_period(), Some(Element::Hf));
+    }
+
+    #[test]
+    fn test_prev_in_period() {
+        assert_eq!(Element::Ne.prev_in_period(), Some(Element::F));
+        assert_eq!(Element::Hf.prev_in_period(), Some(Element::Ba));
+        assert_eq!(Element::Li.prev_in_period(), None);
+        assert_eq!(Element::U.prev_in_period(), None);
+    }
+
+    #[test]
+    fn test_neutron_count() {
+        assert_eq!(Element::C.neutron_count(13), Ok(7));
+        assert_eq!(Element::H.neutron_count(1), Ok(0));
+        assert_eq!(Element::U.neutron_count(238), Ok(146));
+        assert_eq!(Isotope::CARBON_13.neutron_count(), Ok(7));
+        assert_eq!(
+            Element::C.neutron_count(3),
+            Err(Error::InvalidIsotope(Element::C, 3))
+        );
+    }
+
+    #[test]
+    fn test_proton_count() {
+        assert_eq!(Element::H.proton_count(), 1);
+        assert_eq!(Element::O.proton_count(), 8);
+        assert_eq!(Element::Any.proton_count(), 0);
+    }
+
+    #[test]
+    fn test_electron_count() {
+        assert_eq!(Element::O.electron_count(-2), Ok(10));
+        assert_eq!(Element::Na.electron_count(1), Ok(10));
+        assert_eq!(Element::H.electron_count(1), Ok(0));
+        assert_eq!(
+            Element::H.electron_count(2),
+            Err(Error::InvalidFormalCharge(Element::H, 2))
+        );
+        assert_eq!(Element::Og.electron_count(i8::MIN), Ok(246));
+        assert_eq!(
+            Element::Any.electron_count(0),
+            Err(Error::UnsupportedElement(Element::Any))
+        );
+    }
+
+    #[test]
+    fn test_is_hypervalent() {
+        assert_eq!(Element::C.is_hypervalent(0, 5), Ok(true));
+        assert_eq!(Element::C.is_hypervalent(0, 4), Ok(false));
+        assert_eq!(Element::S.is_hypervalent(0, 6), Ok(false));
+        assert_eq!(Element::S.is_hypervalent(0, 7), Ok(true));
+        assert_eq!(Element::N.is_hypervalent(1, 4), Ok(false));
+        assert_eq!(
+            Element::Any.is_hypervalent(0, 1),
+            Err(Error::UnsupportedElement(Element::Any))
+        );
+        assert_eq!(
+            Element::Fe.is_hypervalent(0, 2),
+            Err(Error::UnsupportedElement(Element::Fe))
+        );
+    }
+
+    #[test]
+    fn test_from_str_trimmed() {
+        assert_eq!(Element::from_str_trimmed(" Fe\n"), Ok(Element::Fe));
+        assert_eq!(Element::from_str_trimmed("\tC  "), Ok(Element::C));
+        assert_eq!(Element::from_str_trimmed("Cl"), Ok(Element::Cl));
+        assert_eq!(
+            Element::from_str_trimmed("C l"),
+            Err(Error::InvalidAtomicSymbol("C l".to_owned()))
+        );
+        assert_eq!(
+            Element::from_str_trimmed("  "),
+            Err(Error::InvalidAtomicSymbol("  ".to_owned()))
+        );
+    }
+
+    #[test]
+    fn test_from_xyz_symbol() {
+        assert_eq!(Element::from_xyz_symbol("FE"), Ok(Element::Fe));
+        assert_eq!(Element::from_xyz_symbol("fe"), Ok(Element::Fe));
+        assert_eq!(Element::from_xyz_symbol("hE"), Ok(Element::He));
+        assert_eq!(Element::from_xyz_symbol("CO"), Ok(Element::Co));
+        assert_eq!(Element::from_xyz_symbol(" c "), Ok(Element::C));
+        assert_eq!(
+            Element::from_xyz_symbol("Xx"),
+            Err(Error::InvalidAtomicSymbol("Xx".to_owned()))
+        );
+        assert_eq!(
+            Element::from_xyz_symbol(""),
+            Err(Error::InvalidAtomicSymbol("".to_owned()))
+        );
+    }
+
+    #[test]
+    fn test_is_main_group() {
+        assert!(Element::H.is_main_group());
+        assert!(Element::He.is_main_group());
+        assert!(Element::C.is_main_group());
+        assert!(Element::Ba.is_main_group());
+        assert!(!Element::Fe.is_main_group());
+        assert!(!Element::La.is_main_group());
+        assert!(!Element::Any.is_main_group());
+
+        assert_eq!(
+            (1..=118u8)
+                .filter(|&n| Element::try_from(n).unwrap().is_main_group())
+                .count(),
+            50
+        );
+    }
+
+    #[test]
+    fn test_ascii_periodic_table() {
+        let table = ascii_periodic_table();
+        let lines: Vec<_> = table.lines().collect();
+        assert_eq!(lines.len(), 10);
+        assert!(lines[0].starts_with("H "));
+        assert!(lines[0].ends_with(" He"));
+        assert_eq!(lines[0].len(), 53);
+        assert!(lines[3].starts_with("K  Ca Sc Ti"));
+        assert!(lines[5].starts_with("Cs Ba *  Hf"));
+        assert!(lines[6].ends_with("Og"));
+        assert_eq!(lines[7], "");
+        assert!(lines[8].starts_with("*     La Ce"));
+        assert!(lines[9].starts_with("**    Ac Th"));
+        assert!(lines.iter().all(|line| line.len() <= 120));
+    }
+
+    #[test]
+    fn test_name() {
+        assert_eq!(Element::C.name(), "Carbon");
+        assert_eq!(Element::Fe.name(), "Iron");
+        assert_eq!(Element::Og.name(), "Oganesson");
+        assert_eq!(Element::Any.name(), "Any");
+    }
+
+    #[test]
+    fn test_display() {
         assert_eq!(format!("{}", Element::C), "C");
+        assert_eq!(format!("{:#}", Element::C), "Carbon");
+        assert_eq!(format!("{}", Element::Fe), "Fe");
+        assert_eq!(format!("{:#}", Element::Fe), "Iron");
+        assert_eq!(format!("{}", Element::Any), "*");
     }
 
     #[test]
-    fn test_atomic_weight() {
-        assert_eq!(Element::H.atomic_weight(None).unwrap(), 1.007975);
-        assert_eq!(Element::C.atomic_weight(Some(13)).unwrap(), 13.003355);
+    fn test_parse_any() {
+        assert_eq!(Element::parse_any("C"), Ok(Element::C));
+        assert_eq!(Element::parse_any("6"), Ok(Element::C));
+        assert_eq!(Element::parse_any("006"), Ok(Element::C));
+        assert_eq!(Element::parse_any("0"), Ok(Element::Any));
+        assert_eq!(
+            Element::parse_any("119"),
+            Err(Error::InvalidAtomicNumber(119))
+        );
+        assert_eq!(
+            Element::parse_any("999"),
+            Err(Error::InvalidAtomicSymbol("999".to_owned()))
+        );
+        assert_eq!(
+            Element::parse_any("99999999999"),
+            Err(Error::InvalidAtomicSymbol("99999999999".to_owned()))
+        );
+        assert_eq!(
+            Element::parse_any("6C"),
+            Err(Error::InvalidAtomicSymbol("6C".to_owned()))
+        );
     }
 
     #[test]
-    fn test_valence_electrons() {
-        assert_eq!(Element::C.n_valence_electrons(0).unwrap(), 4);
-        assert_eq!(Element::C.n_valence_electrons(-1).unwrap(), 5);
-        assert_eq!(Element::O.n_valence_electrons(-2).unwrap(), 8);
+    fn test_isotope_pattern() {
+        let pattern = isotope_pattern("CHCl3").unwrap();
+        let intensity = |mass: f64| {
+            pattern
+                .iter()
+                .find(|(m, _)| (m - mass).abs() < 0.5)
+                .map(|&(_, intensity)| intensity)
+                .unwrap()
+        };
+
+        // M, M+2, M+4, M+6 chlorine cluster of roughly 100:96:31:3
+        let monoisotopic_mass = 12.0 + 1.007_825 + 3.0 * 34.968_853;
+        assert!((pattern[0].0 - monoisotopic_mass).abs() < 1e-6);
+        assert_eq!(intensity(118.0), 1.0);
+        assert!((intensity(120.0) - 0.96).abs() < 0.01);
+        assert!((intensity(122.0) - 0.307).abs() < 0.01);
+        assert!((intensity(124.0) - 0.033).abs() < 0.005);
+        assert!(intensity(119.0) < 0.02);
+        assert!(pattern.windows(2).all(|w| w[0].0 < w[1].0));
+
+        let pattern = isotope_pattern("C").unwrap();
+        assert_eq!(pattern.len(), 2);
+        assert!((pattern[1].1 - 0.0107 / 0.9893).abs() < 1e-9);
+
         assert_eq!(
-            Element::F.n_valence_electrons(-2),
-            Err(Error::InvalidFormalCharge("F".to_owned(), -2))
+            isotope_pattern("FeCl3"),
+            Err(Error::UnsupportedElement(Element::Fe))
+        );
+    }
+
+    #[test]
+    fn test_property_table() {
+        let weights = property_table(|element| element.atomic_weight(None).unwrap());
+        assert_eq!(weights[Element::C.atomic_number() as usize], 12.0106);
+        assert_eq!(weights[Element::Any.atomic_number() as usize], 0.0);
+        assert_eq!(weights[118], 294.0);
+
+        let symbols = property_table(|element| element.symbol());
+        assert_eq!(symbols[26], "Fe");
+    }
+
+    #[test]
+    fn test_partial_eq_u8_str() {
+        assert!(Element::C == 6u8);
+        assert!(Element::C != 7u8);
+        assert!(Element::Any == 0u8);
+        assert!(Element::C == "C");
+        assert!(Element::Cl == "Cl");
+        assert!(Element::C != "c");
+        assert!(Element::Cl != "CL");
+        assert!(Element::Any == "*");
+    }
+
+    #[test]
+    fn test_computed_average_mass() {
+        let computed = Element::C.computed_average_mass().unwrap();
+        assert!((computed - Element::C.atomic_weight(None).unwrap()).abs() < 0.001);
+        assert_eq!(Element::Fe.computed_average_mass(), None);
+        assert_eq!(Element::Any.computed_average_mass(), None);
+
+        for atomic_number in 1..=118u8 {
+            let element = Element::try_from(atomic_number).unwrap();
+            match (
+                element.computed_average_mass(),
+                element.atomic_weight_interval(),
+            ) {
+                (Some(computed), Some((lower, upper))) => {
+                    assert!(lower <= computed && computed <= upper, "{element}")
+                }
+                (Some(computed), None) => {
+                    let weight = element.atomic_weight(None).unwrap();
+                    assert!((computed - weight).abs() < 0.001, "{element}");
+                }
+                (None, _) => (),
+            }
+        }
+    }
+
+    #[test]
+    fn test_atomic_weight_with_composition() {
+        let weight = Element::C
+            .atomic_weight_with_composition(&[(12, 0.5), (13, 0.5)])
+            .unwrap();
+        assert!((weight - 12.501_677_5).abs() < 1e-9);
+        assert_eq!(
+            Element::C.atomic_weight_with_composition(&[(12, 0.5), (99, 0.5)]),
+            Err(Error::InvalidIsotope(Element::C, 99))
+        );
+        assert_eq!(
+            Element::C.atomic_weight_with_composition(&[(12, 0.5), (13, 0.4)]),
+            Err(Error::InvalidComposition(Element::C))
+        );
+        assert_eq!(
+            Element::C.atomic_weight_with_composition(&[(12, 1.5), (13, -0.5)]),
+            Err(Error::InvalidComposition(Element::C))
+        );
+        assert_eq!(
+            Element::C.atomic_weight_with_composition(&[]),
+            Err(Error::InvalidComposition(Element::C))
+        );
+    }
+
+    #[test]
+    fn test_group_members() {
+        assert_eq!(
+            Element::group_members(1),
+            vec![
+                Element::H,
+                Element::Li,
+                Element::Na,
+                Element::K,
+                Element::Rb,
+                Element::Cs,
+                Element::Fr
+            ]
+        );
+        assert_eq!(Element::group_members(3), vec![Element::Sc, Element::Y]);
+        assert_eq!(Element::group_members(17).len(), 6);
+        assert!(Element::group_members(0).is_empty());
+        assert!(Element::group_members(19).is_empty());
+    }
+
+    #[test]
+    fn test_period_members() {
+        assert_eq!(Element::period_members(1), vec![Element::H, Element::He]);
+        assert_eq!(Element::period_members(2).len(), 8);
+        assert_eq!(Element::period_members(4).len(), 18);
+        assert_eq!(Element::period_members(6).len(), 32);
+        assert!(Element::period_members(6).contains(&Element::Ce));
+        assert_eq!(Element::period_members(7).last(), Some(&Element::Og));
+        assert!(Element::period_members(0).is_empty());
+        assert!(Element::period_members(8).is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        #[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+        struct Atoms {
+            symbol: Element,
+            #[serde(with = "crate::serde::as_number")]
+            number: Element,
+            #[serde(
+                serialize_with = "crate::serde::serialize_as_number",
+                deserialize_with = "crate::serde::deserialize_from_number"
+            )]
+            other_number: Element,
+        }
+
+        let atoms = Atoms {
+            symbol: Element::Fe,
+            number: Element::C,
+            other_number: Element::Og,
+        };
+        let json = serde_json::to_string(&atoms).unwrap();
+        assert_eq!(json, r#"{"symbol":"Fe","number":6,"other_number":118}"#);
+        assert_eq!(serde_json::from_str::<Atoms>(&json).unwrap(), atoms);
+
+        assert!(
+            serde_json::from_str::<Atoms>(r#"{"symbol":"Xx","number":6,"other_number":118}"#)
+                .is_err()
+        );
+        assert!(serde_json::from_str::<Atoms>(
+            r#"{"symbol":"Fe","number":200,"other_number":118}"#
         )
+        .is_err());
     }
 
     #[test]
-    fn test_valence() {
-        assert_eq!(Element::C.valence(0).unwrap(), 4);
-        assert_eq!(Element::S.valence(0).unwrap(), 2);
+    fn test_format_isotope_unicode() {
+        assert_eq!(Element::C.format_isotope_unicode(13), "¹³C");
+        assert_eq!(Element::U.format_isotope_unicode(235), "²³⁵U");
+        assert_eq!(Element::H.format_isotope_unicode(2), "²H");
+        assert_eq!(Element::Og.format_isotope_unicode(294), "²⁹⁴Og");
+        assert_eq!(Element::Ne.format_isotope_unicode(20), "²⁰Ne");
+    }
+
+    #[test]
+    fn test_parse_isotope_unicode() {
+        assert_eq!(Element::parse_isotope_unicode("¹³C"), Ok((Element::C, 13)));
+        assert_eq!(
+            Element::parse_isotope_unicode("²³⁵U"),
+            Ok((Element::U, 235))
+        );
+        for (element, mass_number) in [(Element::C, 13), (Element::U, 235), (Element::Ne, 20)] {
+            let s = element.format_isotope_unicode(mass_number);
+            assert_eq!(
+                Element::parse_isotope_unicode(&s),
+                Ok((element, mass_number))
+            );
+        }
+        assert_eq!(
+            Element::parse_isotope_unicode("C"),
+            Err(Error::InvalidAtomicSymbol("C".to_owned()))
+        );
+        assert_eq!(
+            Element::parse_isotope_unicode("¹³"),
+            Err(Error::InvalidAtomicSymbol("¹³".to_owned()))
+        );
+        assert_eq!(
+            Element::parse_isotope_unicode("¹³Xx"),
+            Err(Error::InvalidAtomicSymbol("¹³Xx".to_owned()))
+        );
+        assert_eq!(
+            Element::parse_isotope_unicode("⁹⁹⁹⁹⁹⁹C"),
+            Err(Error::InvalidAtomicSymbol("⁹⁹⁹⁹⁹⁹C".to_owned()))
+        );
+    }
+
+    #[test]
+    fn test_n_valence_electrons_extreme_formal_charge() {
+        assert_eq!(
+            Element::C.n_valence_electrons(i8::MIN),
+            Err(Error::InvalidFormalCharge(Element::C, i8::MIN))
+        );
+        assert_eq!(
+            Element::C.n_valence_electrons(i8::MAX),
+            Err(Error::InvalidFormalCharge(Element::C, i8::MAX))
+        );
+        assert_eq!(
+            Element::C.valence(i8::MIN),
+            Err(Error::InvalidFormalCharge(Element::C, i8::MIN))
+        );
+        for formal_charge in i8::MIN..=i8::MAX {
+            let result = Element::C.n_valence_electrons(formal_charge);
+            assert_eq!(result.is_ok(), (-4..=4).contains(&formal_charge));
+        }
+    }
+
+    #[test]
+    fn test_electronegativity_difference() {
+        let diff = electronegativity_difference(Element::Na, Element::Cl).unwrap();
+        assert!((diff - 2.23).abs() < 1e-9);
+        assert_eq!(
+            electronegativity_difference(Element::Cl, Element::Na),
+            electronegativity_difference(Element::Na, Element::Cl)
+        );
+        assert_eq!(electronegativity_difference(Element::C, Element::Ne), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_bond_ionicity() {
+        let na_cl = bond_ionicity(Element::Na, Element::Cl).unwrap();
+        assert!((na_cl - 0.711).abs() < 1e-3);
+        let c_h = bond_ionicity(Element::C, Element::H).unwrap();
+        assert!((c_h - 0.030).abs() < 1e-3);
+        assert_eq!(bond_ionicity(Element::C, Element::C), Some(0.0));
+        assert_eq!(bond_ionicity(Element::He, Element::H), None);
+    }
+
+    #[test]
+    fn test_category() {
+        assert_eq!(Element::H.category(), Some(Category::Nonmetal));
+        assert_eq!(Element::C.category(), Some(Category::Nonmetal));
+        assert_eq!(Element::Ne.category(), Some(Category::NobleGas));
+        assert_eq!(Element::Na.category(), Some(Category::AlkaliMetal));
+        assert_eq!(Element::Ca.category(), Some(Category::AlkalineEarthMetal));
+        assert_eq!(Element::Si.category(), Some(Category::Metalloid));
+        assert_eq!(Element::Cl.category(), Some(Category::Halogen));
+        assert_eq!(Element::Fe.category(), Some(Category::TransitionMetal));
+        assert_eq!(Element::Pb.category(), Some(Category::PostTransitionMetal));
+        assert_eq!(Element::Nd.category(), Some(Category::Lanthanide));
+        assert_eq!(Element::U.category(), Some(Category::Actinide));
+        assert_eq!(Element::Any.category(), None);
+
+        for atomic_number in 1..=118u8 {
+            assert!(Element::try_from(atomic_number)
+                .unwrap()
+                .category()
+                .is_some());
+        }
+    }
+
+    #[test]
+    fn test_valence_extensions() {
+        assert_eq!(Element::Si.n_valence_electrons(0), Ok(4));
+        assert_eq!(Element::Si.valence(0), Ok(4));
+        assert_eq!(Element::Si.valences(0), Ok(&[4][..]));
+        assert_eq!(Element::Se.n_valence_electrons(0), Ok(6));
+        assert_eq!(Element::Se.valence(0), Ok(2));
+        assert_eq!(Element::Se.valences(0), Ok(&[2, 4, 6][..]));
+        assert_eq!(Element::B.valence(0), Ok(3));
+        assert_eq!(const_valence(Element::Si), Some(4));
+        assert_eq!(const_valence(Element::Se), Some(2));
+        assert!(!Element::Si.is_organic_subset());
+        assert!(!Element::Se.is_organic_subset());
+    }
+
+    #[test]
+    fn test_unpaired_electrons() {
+        assert_eq!(Element::H.unpaired_electrons(), Some(1));
+        assert_eq!(Element::He.unpaired_electrons(), Some(0));
+        assert_eq!(Element::N.unpaired_electrons(), Some(3));
+        assert_eq!(Element::O.unpaired_electrons(), Some(2));
+        assert_eq!(Element::Fe.unpaired_electrons(), Some(4));
+        assert_eq!(Element::Cr.unpaired_electrons(), Some(6));
+        assert_eq!(Element::Cu.unpaired_electrons(), Some(1));
+        assert_eq!(Element::Pd.unpaired_electrons(), Some(0));
+        assert_eq!(Element::Gd.unpaired_electrons(), Some(8));
+        assert_eq!(Element::Any.unpaired_electrons(), None);
+    }
+
+    #[test]
+    fn test_atomic_weight_or_nominal() {
+        assert_eq!(Element::C.atomic_weight_or_nominal(None), 12.010_6);
+        assert_eq!(Element::C.atomic_weight_or_nominal(Some(13)), 13.003_355);
+        assert_eq!(Element::C.atomic_weight_or_nominal(Some(11)), 11.0);
+        assert_eq!(Element::C.atomic_weight_or_nominal(Some(14)), 14.003_242);
+        assert_eq!(Element::C.atomic_weight_or_nominal(Some(15)), 15.0);
+        assert_eq!(Element::Any.atomic_weight_or_nominal(None), 0.0);
+    }
+
+    #[test]
+    fn test_valence_noble_gases() {
+        assert_eq!(Element::He.n_valence_electrons(0), Ok(2));
+        assert_eq!(Element::He.valence(0), Ok(0));
+        assert_eq!(Element::He.valence(1), Ok(1));
+        assert_eq!(
+            Element::He.valence(-1),
+            Err(Error::InvalidFormalCharge(Element::He, -1))
+        );
+        assert_eq!(Element::Ne.n_valence_electrons(0), Ok(8));
+        assert_eq!(Element::Ne.valence(0), Ok(0));
+        assert_eq!(Element::Ne.valences(0), Ok(&[0][..]));
+        assert_eq!(Element::Xe.valence(0), Ok(0));
+        assert_eq!(Element::Xe.valence(1), Ok(1));
+        assert_eq!(const_valence(Element::Xe), Some(0));
+        for element in [
+            Element::He,
+            Element::Ne,
+            Element::Ar,
+            Element::Kr,
+            Element::Xe,
+            Element::Rn,
+            Element::Og,
+        ] {
+            assert_eq!(element.valence(0), Ok(0));
+            assert_eq!(const_valence(element), Some(0));
+        }
+    }
+
+    #[test]
+    fn test_from_ascii() {
+        assert_eq!(Element::from_ascii(b"Fe"), Ok(Element::Fe));
+        assert_eq!(Element::from_ascii(b"c"), Ok(Element::C));
+        assert_eq!(Element::from_ascii(b"*"), Ok(Element::Any));
+        assert_eq!(
+            Element::from_ascii(b"Xx"),
+            Err(Error::InvalidAtomicSymbol("Xx".to_owned()))
+        );
+        assert_eq!(
+            Element::from_ascii(b""),
+            Err(Error::InvalidAtomicSymbol("".to_owned()))
+        );
+        assert_eq!(
+            Element::from_ascii(b"Fee"),
+            Err(Error::InvalidAtomicSymbol("Fee".to_owned()))
+        );
+
+        for atomic_number in 0..=118u8 {
+            let element = Element::from_atomic_number(atomic_number).unwrap();
+            let symbol = element.symbol();
+            assert_eq!(Element::from_ascii(symbol.as_bytes()), Ok(element));
+            assert_eq!(
+                Element::from_ascii(symbol.to_uppercase().as_bytes()),
+                Ok(element)
+            );
+            assert_eq!(
+                Element::from_ascii(symbol.to_lowercase().as_bytes()),
+                Ok(element)
+            );
+        }
+    }
+
+    #[test]
+    fn test_cpk_color_hex() {
+        assert_eq!(Element::H.cpk_color_hex(), "#FFFFFF");
+        assert_eq!(Element::C.cpk_color_hex(), "#909090");
+        assert_eq!(Element::O.cpk_color_hex(), "#FF0D0D");
+        assert_eq!(Element::N.cpk_color_hex(), "#3050F8");
+        assert_eq!(Element::Any.cpk_color_hex(), "#FF1493");
+    }
+
+    #[test]
+    fn test_total_atomic_weight() {
+        let methane = [Element::C, Element::H, Element::H, Element::H, Element::H];
+        assert!((total_atomic_weight(&methane).unwrap() - 16.04).abs() < 1e-2);
+        assert_eq!(total_atomic_weight(&methane), molecular_weight("CH4"));
+        assert_eq!(total_atomic_weight(&[]), Ok(0.0));
+    }
+
+    #[test]
+    fn test_from_legacy_symbol() {
+        assert_eq!(Element::from_legacy_symbol("Uuo"), Ok(Element::Og));
+        assert_eq!(Element::from_legacy_symbol("Uut"), Ok(Element::Nh));
+        assert_eq!(Element::from_legacy_symbol("Uup"), Ok(Element::Mc));
+        assert_eq!(Element::from_legacy_symbol("Uus"), Ok(Element::Ts));
+        assert_eq!(Element::from_legacy_symbol("Uuq"), Ok(Element::Fl));
+        assert_eq!(Element::from_legacy_symbol("Uuh"), Ok(Element::Lv));
+        assert_eq!(Element::from_legacy_symbol("Uub"), Ok(Element::Cn));
+        assert_eq!(Element::from_legacy_symbol("uuo"), Ok(Element::Og));
+        assert_eq!(Element::from_legacy_symbol("Og"), Ok(Element::Og));
+        assert_eq!(Element::from_legacy_symbol("Fe"), Ok(Element::Fe));
+        assert_eq!(
+            Element::from_legacy_symbol("Uux"),
+            Err(Error::InvalidAtomicSymbol("Uux".to_owned()))
+        );
+        assert!("Uuo".parse::<Element>().is_err());
     }
 }